@@ -1,9 +1,116 @@
+use std::collections::{BTreeSet, HashSet};
+
+use num::{
+    traits::{float::TotalOrder, real::Real},
+    BigRational, Zero,
+};
+
 use crate::{
+    annulus::Annulus,
+    ball::{Ball, Sphere},
     circle::Circle2D,
-    geometry::point::PointLike,
-    predicates::in_circle::{DefaultInCircle, InCircle},
+    disk::{Disk, DiskCircle2D, DiskLike},
+    ellipse::{Ellipse, Ellipse2D},
+    geometry::{
+        num::{ConstTwo, Finite, ToExactRational},
+        point::PointLike,
+        weighted::{weighted_offset2d_three, weighted_offset2d_two},
+    },
+    predicates::{
+        disk_in_circle::{DefaultDiskInCircle, DiskInCircle},
+        in_circle::{DefaultInCircle, InCircle, InCircleState},
+        orientation::{
+            DefaultOrientation, DefaultOrientationArea, Orientation, OrientationArea,
+            OrientationState,
+        },
+    },
+    random::SplitMix64,
 };
 
+/// The result of [`smallest_enclosing_circle_indexed`], i.e., the smallest enclosing circle together with the positions of its spanning points in the input.
+#[derive(PartialEq, Clone, Debug)]
+pub struct EnclosingCircleResult<Point> {
+    /// The smallest enclosing circle.
+    pub circle: Circle2D<Point>,
+    /// Indices (positions in the input iterator) of the points that span [`EnclosingCircleResult::circle`], in the same order as they appear in the circle's variant.
+    pub support_indices: Vec<usize>,
+}
+
+/// The result of [`smallest_enclosing_circle_of_circles_indexed`], i.e., the smallest circle that encloses all disks together with the positions of its spanning disks and of all touching disks in the input.
+#[derive(PartialEq, Clone, Debug)]
+pub struct EnclosingCircleOfCirclesResult<D> {
+    /// The smallest circle that encloses all disks.
+    pub circle: DiskCircle2D<D>,
+    /// Indices (positions in the input iterator) of the disks that span [`EnclosingCircleOfCirclesResult::circle`], in the same order as they appear in the circle's variant.
+    pub support_indices: Vec<usize>,
+    /// Indices (positions in the input iterator) of all disks that touch [`EnclosingCircleOfCirclesResult::circle`] from the inside (i.e., that are *tight*), in increasing order. This includes the spanning disks.
+    pub tight_indices: Vec<usize>,
+}
+
+/// The result of [`smallest_enclosing_circle_with_outliers`], i.e., the smallest circle that encloses all but the outliers, together with the positions of its spanning points and of the outliers in the input.
+#[derive(PartialEq, Clone, Debug)]
+pub struct OutlierCircleResult<Point> {
+    /// The smallest circle that encloses all points except for the outliers.
+    pub circle: Circle2D<Point>,
+    /// Indices (positions in the input iterator) of the points that span [`OutlierCircleResult::circle`], in the same order as they appear in the circle's variant.
+    pub support_indices: Vec<usize>,
+    /// Indices (positions in the input iterator) of the points that are not enclosed by [`OutlierCircleResult::circle`], in ascending order.
+    pub outlier_indices: Vec<usize>,
+}
+
+/// The result of [`smallest_enclosing_circle_k_of_n`], i.e., the smallest circle that encloses at least `k` points, together with the positions of the enclosed points in the input.
+#[derive(PartialEq, Clone, Debug)]
+pub struct CoveringCircleResult<Point> {
+    /// The smallest circle that encloses at least `k` points.
+    pub circle: Circle2D<Point>,
+    /// Indices (positions in the input iterator) of the points that are enclosed by [`CoveringCircleResult::circle`], in ascending order.
+    pub covered_indices: Vec<usize>,
+}
+
+/// Wraps a point together with its position in the input, so that the algorithms can keep track of where the spanning points came from.
+/// Equality only considers the point itself, i.e., duplicates at different positions are still detected as such.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Indexed<Point> {
+    pub(crate) index: usize,
+    pub(crate) point: Point,
+}
+
+impl<Point: PartialEq> PartialEq for Indexed<Point> {
+    fn eq(&self, other: &Self) -> bool {
+        self.point == other.point
+    }
+}
+
+impl<Point: PointLike<C, 2>, C> PointLike<C, 2> for Indexed<Point> {
+    fn coordinates(&self) -> [C; 2] {
+        self.point.coordinates()
+    }
+}
+
+impl<D: DiskLike<C>, C> DiskLike<C> for Indexed<D> {
+    fn center(&self) -> [C; 2] {
+        self.point.center()
+    }
+
+    fn radius(&self) -> C {
+        self.point.radius()
+    }
+}
+
+impl<Point> From<Circle2D<Indexed<Point>>> for EnclosingCircleResult<Point> {
+    fn from(circle: Circle2D<Indexed<Point>>) -> Self {
+        let mut support_indices = Vec::new();
+        let circle = circle.map(|element| {
+            support_indices.push(element.index);
+            element.point
+        });
+        EnclosingCircleResult {
+            circle,
+            support_indices,
+        }
+    }
+}
+
 enum State<Point> {
     S0,
     S1,
@@ -21,27 +128,48 @@ enum State<Point> {
 /// use smallest_enclosing_circle::predicates::in_circle::DefaultInCircle;
 ///
 /// // Input: Four corner points of square box of unit size
-/// let circle = smallest_enclosing_circle_with_predicate::<_, DefaultInCircle>([[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
+/// let circle = smallest_enclosing_circle_with_predicate::<_, DefaultInCircle, _>([[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
 /// assert_eq!(circle.center(), Some([0.5, 0.5]));
 /// assert_eq!(circle.radius(), Some(f64::sqrt(2.) / 2.));
 /// ```
-pub fn smallest_enclosing_circle_with_predicate<Point, InCirclePredicate>(
+pub fn smallest_enclosing_circle_with_predicate<Point, InCirclePredicate, C>(
     points: impl IntoIterator<Item = Point>,
 ) -> Circle2D<Point>
 where
-    Point: PartialEq + PointLike<f64, 2> + Copy,
-    InCirclePredicate: InCircle<f64>,
+    Point: PartialEq + PointLike<C, 2> + Copy,
+    InCirclePredicate: InCircle<C>,
+    C: PartialEq,
+    DefaultOrientation: Orientation<C>,
 {
-    let mut p: Vec<Point> = points.into_iter().collect();
-    let mut r = Vec::new();
-    let mut circle = Circle2D::None;
+    welzl(
+        points.into_iter().collect(),
+        Vec::new(),
+        3,
+        |r| Circle2D::new(r),
+        |circle, element| !circle.contains_with_predicate::<Point, InCirclePredicate, C>(element),
+    )
+}
+
+/// The iterative version of Welzl's algorithm, generic over the kind of elements to enclose.
+///
+/// Computes the smallest enclosing object of the elements in `p` that has the elements in `r` on its boundary. `basis` constructs the smallest enclosing object from up to `max_support` elements on its boundary, and `violates` determines whether an element is not enclosed by such an object.
+pub(crate) fn welzl<Element, Basis>(
+    mut p: Vec<Element>,
+    mut r: Vec<Element>,
+    max_support: usize,
+    mut basis: impl FnMut(&[Element]) -> Basis,
+    mut violates: impl FnMut(&Basis, &Element) -> bool,
+) -> Basis
+where
+    Element: Copy,
+{
+    let mut result = None;
     let mut stack = Vec::from([State::S0]);
-    while !stack.is_empty() {
-        let state = stack.pop().unwrap();
+    while let Some(state) = stack.pop() {
         match state {
             State::S0 => {
-                if p.len() == 0 || r.len() == 3 {
-                    circle = Circle2D::new(&r);
+                if p.is_empty() || r.len() >= max_support {
+                    result = Some(basis(&r));
                 } else {
                     stack.push(State::S1);
                 }
@@ -52,9 +180,11 @@ where
                 stack.push(State::S0);
             }
             State::S2(element) => {
+                let current = result.as_ref().unwrap();
+                let violated = violates(current, &element);
                 stack.push(State::S3(element));
 
-                if !circle.contains_with_predicate::<Point, InCirclePredicate>(&element) {
+                if violated {
                     r.push(element);
                     stack.push(State::S4);
                     stack.push(State::S0);
@@ -68,106 +198,1209 @@ where
             }
         }
     }
-    circle
+    result.unwrap()
+}
+
+/// Takes an iterator over two-dimensional points and returns the smallest circle that encloses all points.
+///
+/// Iterative version of Welzl's algorithm, which was originally formulated as recursive algorithm.
+/// The expected input is an iterator of two-dimensional points (e.g., `[f64; 2]` or `[f32; 2]` coordinate pairs) with actual numbers (no NaNs or Infinites). Duplicates are allowed.
+/// This is not checked; use [`crate::validation::smallest_enclosing_circle_validated`] for untrusted inputs.
+/// Note that the original algorithm is based on randomizing the order of input points.
+/// This is omitted in this function, however randomization can be done by the caller in advance, or by using [`smallest_enclosing_circle_randomized`] instead.
+/// The advantage over the recursive algorithm is that large problem sizes do not run into call stack problems.
+/// The result is a [`Circle2D`] enum.
+///
+/// Welzl's algorithm solves this problem in expected `O(n)` runtime. Please note that this only holds for randomized inputs (i.e., you may want to shuffle your input stream in advance, or use [`smallest_enclosing_circle_randomized`]).
+///
+/// The implementation is based on the following work:
+///
+/// Welzl, E. (1991). Smallest enclosing disks (balls and ellipsoids).
+/// In New results and new trends in computer science (pp. 359-370).
+/// Springer, Berlin, Heidelberg.
+///
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::smallest_enclosing_circle;
+///
+/// // Input: Four corner points of square box of unit size
+/// let circle = smallest_enclosing_circle([[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
+/// assert_eq!(circle.center(), Some([0.5, 0.5]));
+/// assert_eq!(circle.radius(), Some(f64::sqrt(2.) / 2.));
+/// ```
+pub fn smallest_enclosing_circle<Point, C>(
+    points: impl IntoIterator<Item = Point>,
+) -> Circle2D<Point>
+where
+    Point: PartialEq + PointLike<C, 2> + Copy,
+    C: PartialEq,
+    DefaultInCircle: InCircle<C>,
+    DefaultOrientation: Orientation<C>,
+{
+    smallest_enclosing_circle_with_predicate::<Point, DefaultInCircle, C>(points)
+}
+
+/// See [`smallest_enclosing_circle_randomized`]. Additionally, supports a custom [`InCircle`] predicate.
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::smallest_enclosing_circle_randomized_with_predicate;
+/// use smallest_enclosing_circle::predicates::in_circle::DefaultInCircle;
+///
+/// // Input: Four corner points of square box of unit size
+/// let circle = smallest_enclosing_circle_randomized_with_predicate::<_, DefaultInCircle, _>([[0., 0.], [1., 0.], [1., 1.], [0., 1.]], 42);
+/// assert_eq!(circle.center(), Some([0.5, 0.5]));
+/// assert_eq!(circle.radius(), Some(f64::sqrt(2.) / 2.));
+/// ```
+pub fn smallest_enclosing_circle_randomized_with_predicate<Point, InCirclePredicate, C>(
+    points: impl IntoIterator<Item = Point>,
+    seed: u64,
+) -> Circle2D<Point>
+where
+    Point: PartialEq + PointLike<C, 2> + Copy,
+    InCirclePredicate: InCircle<C>,
+    C: PartialEq,
+    DefaultOrientation: Orientation<C>,
+{
+    let mut p: Vec<Point> = points.into_iter().collect();
+    SplitMix64::new(seed).shuffle(&mut p);
+    smallest_enclosing_circle_with_predicate::<Point, InCirclePredicate, C>(p)
+}
+
+/// Same as [`smallest_enclosing_circle`], but shuffles the input points before running Welzl's algorithm, so the expected `O(n)` runtime also holds for sorted or adversarial inputs.
+///
+/// The shuffle is driven by a small built-in pseudo random number generator initialized with `seed`. The same seed always produces the same order, and thus the same result, across runs and platforms.
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::smallest_enclosing_circle_randomized;
+///
+/// // Input: Four corner points of square box of unit size
+/// let circle = smallest_enclosing_circle_randomized([[0., 0.], [1., 0.], [1., 1.], [0., 1.]], 42);
+/// assert_eq!(circle.center(), Some([0.5, 0.5]));
+/// assert_eq!(circle.radius(), Some(f64::sqrt(2.) / 2.));
+/// ```
+pub fn smallest_enclosing_circle_randomized<Point, C>(
+    points: impl IntoIterator<Item = Point>,
+    seed: u64,
+) -> Circle2D<Point>
+where
+    Point: PartialEq + PointLike<C, 2> + Copy,
+    C: PartialEq,
+    DefaultInCircle: InCircle<C>,
+    DefaultOrientation: Orientation<C>,
+{
+    smallest_enclosing_circle_randomized_with_predicate::<Point, DefaultInCircle, C>(points, seed)
+}
+
+/// See [`smallest_enclosing_circle_move_to_front`]. Additionally, supports a custom [`InCircle`] predicate.
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::algorithm::smallest_enclosing_circle_move_to_front_with_predicate;
+/// use smallest_enclosing_circle::predicates::in_circle::DefaultInCircle;
+///
+/// // Input: Four corner points of square box of unit size
+/// let circle = smallest_enclosing_circle_move_to_front_with_predicate::<_, DefaultInCircle, _>([[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
+/// assert_eq!(circle.center(), Some([0.5, 0.5]));
+/// assert_eq!(circle.radius(), Some(f64::sqrt(2.) / 2.));
+/// ```
+pub fn smallest_enclosing_circle_move_to_front_with_predicate<Point, InCirclePredicate, C>(
+    points: impl IntoIterator<Item = Point>,
+) -> Circle2D<Point>
+where
+    Point: PartialEq + PointLike<C, 2> + Copy,
+    InCirclePredicate: InCircle<C>,
+    C: PartialEq,
+    DefaultOrientation: Orientation<C>,
+{
+    fn move_to_front<Point, InCirclePredicate, C>(
+        p: &mut [Point],
+        end: usize,
+        r: &mut Vec<Point>,
+    ) -> Circle2D<Point>
+    where
+        Point: PartialEq + PointLike<C, 2> + Copy,
+        InCirclePredicate: InCircle<C>,
+        C: PartialEq,
+        DefaultOrientation: Orientation<C>,
+    {
+        let mut circle = Circle2D::new(r);
+        if r.len() == 3 {
+            return circle;
+        }
+        for i in 0..end {
+            let element = p[i];
+            if !circle.contains_with_predicate::<Point, InCirclePredicate, C>(&element) {
+                r.push(element);
+                circle = move_to_front::<Point, InCirclePredicate, C>(p, i, r);
+                r.pop();
+                p[..=i].rotate_right(1);
+            }
+        }
+        circle
+    }
+
+    let mut p: Vec<Point> = points.into_iter().collect();
+    let end = p.len();
+    move_to_front::<Point, InCirclePredicate, C>(&mut p, end, &mut Vec::new())
+}
+
+/// Takes an iterator over two-dimensional points and returns the smallest circle that encloses all points, using the move-to-front heuristic.
+///
+/// Instead of keeping the input order fixed, every point that is found outside of the current circle is moved to the front of the point list.
+/// Such points are likely to span (or to be close to) the final circle, so they are tested first in all subsequent passes.
+/// This makes the algorithm considerably faster on inputs that are sorted (e.g., spatially sorted point clouds), for which the order-preserving [`smallest_enclosing_circle`] performs poorly unless the input is shuffled.
+///
+/// The implementation is recursive, but the recursion depth is bounded by the number of spanning points (at most 4 nested calls), so large problem sizes do not run into call stack problems.
+/// The result is a [`Circle2D`] enum.
+///
+/// The move-to-front heuristic is described in the following work:
+///
+/// Gärtner, B. (1999). Fast and robust smallest enclosing balls.
+/// In European Symposium on Algorithms (pp. 325-338).
+/// Springer, Berlin, Heidelberg.
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::smallest_enclosing_circle_move_to_front;
+///
+/// // Input: Four corner points of square box of unit size
+/// let circle = smallest_enclosing_circle_move_to_front([[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
+/// assert_eq!(circle.center(), Some([0.5, 0.5]));
+/// assert_eq!(circle.radius(), Some(f64::sqrt(2.) / 2.));
+/// ```
+pub fn smallest_enclosing_circle_move_to_front<Point, C>(
+    points: impl IntoIterator<Item = Point>,
+) -> Circle2D<Point>
+where
+    Point: PartialEq + PointLike<C, 2> + Copy,
+    C: PartialEq,
+    DefaultInCircle: InCircle<C>,
+    DefaultOrientation: Orientation<C>,
+{
+    smallest_enclosing_circle_move_to_front_with_predicate::<Point, DefaultInCircle, C>(points)
+}
+
+/// See [`smallest_enclosing_circle_indexed`]. Additionally, supports a custom [`InCircle`] predicate.
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::smallest_enclosing_circle_indexed_with_predicate;
+/// use smallest_enclosing_circle::predicates::in_circle::DefaultInCircle;
+///
+/// // Input: Four corner points of square box of unit size
+/// let result = smallest_enclosing_circle_indexed_with_predicate::<_, DefaultInCircle, _>([[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
+/// assert_eq!(result.circle.center(), Some([0.5, 0.5]));
+/// assert_eq!(result.support_indices.len(), 2);
+/// ```
+pub fn smallest_enclosing_circle_indexed_with_predicate<Point, InCirclePredicate, C>(
+    points: impl IntoIterator<Item = Point>,
+) -> EnclosingCircleResult<Point>
+where
+    Point: PartialEq + PointLike<C, 2> + Copy,
+    InCirclePredicate: InCircle<C>,
+    C: PartialEq,
+    DefaultOrientation: Orientation<C>,
+{
+    let points = points
+        .into_iter()
+        .enumerate()
+        .map(|(index, point)| Indexed { index, point });
+    smallest_enclosing_circle_with_predicate::<_, InCirclePredicate, C>(points).into()
+}
+
+/// Same as [`smallest_enclosing_circle`], but additionally returns the indices of the spanning points within the input.
+///
+/// This allows to identify the support set (e.g., to highlight or remove those elements, or to map them back to other records) without searching the input for points that lie on the circle.
+/// If the input contains duplicates of a spanning point, only one of their indices is reported.
+/// The result is an [`EnclosingCircleResult`].
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::smallest_enclosing_circle_indexed;
+///
+/// let result = smallest_enclosing_circle_indexed([[0., 0.], [0.5, 0.], [2., 0.], [1., 0.5]]);
+/// assert_eq!(result.circle.center(), Some([1.0, 0.0]));
+///
+/// let mut support_indices = result.support_indices.clone();
+/// support_indices.sort();
+/// assert_eq!(support_indices, vec![0, 2]);
+/// ```
+pub fn smallest_enclosing_circle_indexed<Point, C>(
+    points: impl IntoIterator<Item = Point>,
+) -> EnclosingCircleResult<Point>
+where
+    Point: PartialEq + PointLike<C, 2> + Copy,
+    C: PartialEq,
+    DefaultInCircle: InCircle<C>,
+    DefaultOrientation: Orientation<C>,
+{
+    smallest_enclosing_circle_indexed_with_predicate::<Point, DefaultInCircle, C>(points)
+}
+
+/// See [`smallest_enclosing_circle_with_outliers`]. Additionally, supports a custom [`InCircle`] predicate.
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::smallest_enclosing_circle_with_outliers_with_predicate;
+/// use smallest_enclosing_circle::predicates::in_circle::DefaultInCircle;
+///
+/// let result = smallest_enclosing_circle_with_outliers_with_predicate::<_, DefaultInCircle, _>([[0., 0.], [1., 0.], [9., 9.]], 1);
+/// assert_eq!(result.outlier_indices, vec![2]);
+/// ```
+pub fn smallest_enclosing_circle_with_outliers_with_predicate<Point, InCirclePredicate, C>(
+    points: impl IntoIterator<Item = Point>,
+    k: usize,
+) -> OutlierCircleResult<Point>
+where
+    Point: PartialEq + PointLike<C, 2> + Copy,
+    InCirclePredicate: InCircle<C>,
+    C: PartialEq + ToExactRational,
+    DefaultOrientation: Orientation<C>,
+{
+    struct Search {
+        visited: HashSet<Vec<usize>>,
+        best: Option<(BigRational, usize, Vec<usize>)>,
+    }
+
+    fn search<Point, InCirclePredicate, C>(
+        points: &[Indexed<Point>],
+        removed: &mut Vec<usize>,
+        k: usize,
+        state: &mut Search,
+    ) where
+        Point: PartialEq + PointLike<C, 2> + Copy,
+        InCirclePredicate: InCircle<C>,
+        C: PartialEq + ToExactRational,
+        DefaultOrientation: Orientation<C>,
+    {
+        let mut key = removed.clone();
+        key.sort_unstable();
+        if !state.visited.insert(key.clone()) {
+            return;
+        }
+        let remaining: Vec<Indexed<Point>> = points
+            .iter()
+            .filter(|p| !removed.contains(&p.index))
+            .copied()
+            .collect();
+        let result: EnclosingCircleResult<Point> =
+            smallest_enclosing_circle_with_predicate::<_, InCirclePredicate, C>(remaining).into();
+        // Circles spanned by at most one point have radius zero.
+        let squared_radius = result
+            .circle
+            .exact_squared_radius()
+            .unwrap_or_else(BigRational::zero);
+        let improves = match &state.best {
+            None => true,
+            Some((best, count, _)) => {
+                squared_radius < *best || (squared_radius == *best && key.len() < *count)
+            }
+        };
+        if improves {
+            state.best = Some((squared_radius, key.len(), key));
+        }
+        // The optimal circle either encloses all spanning points of the current circle (and then equals it), or excludes at least one of them.
+        if removed.len() < k {
+            for index in result.support_indices {
+                removed.push(index);
+                search::<Point, InCirclePredicate, C>(points, removed, k, state);
+                removed.pop();
+            }
+        }
+    }
+
+    let mut points: Vec<Indexed<Point>> = points
+        .into_iter()
+        .enumerate()
+        .map(|(index, point)| Indexed { index, point })
+        .collect();
+    // Subsets of a shuffled sequence are shuffled as well, so every subproblem runs in expected linear time.
+    SplitMix64::new(0).shuffle(&mut points);
+    let mut state = Search {
+        visited: HashSet::new(),
+        best: None,
+    };
+    search::<Point, InCirclePredicate, C>(&points, &mut Vec::new(), k, &mut state);
+
+    let removed = state.best.map(|best| best.2).unwrap_or_default();
+    let result: EnclosingCircleResult<Point> =
+        smallest_enclosing_circle_with_predicate::<_, InCirclePredicate, C>(
+            points
+                .iter()
+                .filter(|p| removed.binary_search(&p.index).is_err())
+                .copied(),
+        )
+        .into();
+    // Removed points may still lie on the boundary of the final circle, only the ones outside are outliers.
+    let mut outlier_indices: Vec<usize> = points
+        .iter()
+        .filter(|p| {
+            !result
+                .circle
+                .contains_with_predicate::<Point, InCirclePredicate, C>(&p.point)
+        })
+        .map(|p| p.index)
+        .collect();
+    outlier_indices.sort_unstable();
+    OutlierCircleResult {
+        circle: result.circle,
+        support_indices: result.support_indices,
+        outlier_indices,
+    }
+}
+
+/// Takes an iterator over two-dimensional points and returns the smallest circle that encloses all but at most `k` of the points, together with the indices of the excluded points (the *outliers*).
+///
+/// The smallest circle with outliers either encloses all spanning points of the smallest enclosing circle of the remaining points (and then equals it), or excludes at least one of them.
+/// Thus, the problem is solved exactly by branching over the (at most three) spanning points, up to depth `k`, which takes `O(3^k n)` expected time: this is intended for a small number of outliers.
+/// Radii are compared with exact rational arithmetic, ties are broken in favor of fewer outliers.
+/// The result is an [`OutlierCircleResult`].
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::{smallest_enclosing_circle_with_outliers, Circle2D};
+///
+/// let points = [[0., 0.], [1., 0.], [100., 100.], [1., 1.], [0., 1.], [-50., 0.]];
+/// let result = smallest_enclosing_circle_with_outliers(points, 2);
+/// assert_eq!(result.outlier_indices, vec![2, 5]);
+/// assert_eq!(result.circle.radius(), Some(f64::sqrt(2.) / 2.));
+/// ```
+pub fn smallest_enclosing_circle_with_outliers<Point, C>(
+    points: impl IntoIterator<Item = Point>,
+    k: usize,
+) -> OutlierCircleResult<Point>
+where
+    Point: PartialEq + PointLike<C, 2> + Copy,
+    C: PartialEq + ToExactRational,
+    DefaultInCircle: InCircle<C>,
+    DefaultOrientation: Orientation<C>,
+{
+    smallest_enclosing_circle_with_outliers_with_predicate::<Point, DefaultInCircle, C>(points, k)
+}
+
+/// See [`smallest_enclosing_circle_k_of_n`]. Additionally, supports a custom [`InCircle`] predicate.
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::smallest_enclosing_circle_k_of_n_with_predicate;
+/// use smallest_enclosing_circle::predicates::in_circle::DefaultInCircle;
+///
+/// let result = smallest_enclosing_circle_k_of_n_with_predicate::<_, DefaultInCircle, _>([[0., 0.], [1., 0.], [9., 9.]], 2);
+/// assert_eq!(result.covered_indices, vec![0, 1]);
+/// ```
+pub fn smallest_enclosing_circle_k_of_n_with_predicate<Point, InCirclePredicate, C>(
+    points: impl IntoIterator<Item = Point>,
+    k: usize,
+) -> CoveringCircleResult<Point>
+where
+    Point: PartialEq + PointLike<C, 2> + Copy,
+    InCirclePredicate: InCircle<C>,
+    C: PartialEq + ToExactRational,
+    DefaultOrientation: Orientation<C>,
+{
+    let points: Vec<Point> = points.into_iter().collect();
+    let n = points.len();
+    assert!(
+        k <= n,
+        "cannot enclose {} points, only {} points were supplied",
+        k,
+        n
+    );
+    let covered = |circle: &Circle2D<Point>| -> Vec<usize> {
+        (0..n)
+            .filter(|i| circle.contains_with_predicate::<Point, InCirclePredicate, C>(&points[*i]))
+            .collect()
+    };
+    if k == 0 {
+        return CoveringCircleResult {
+            circle: Circle2D::None,
+            covered_indices: Vec::new(),
+        };
+    }
+
+    // The smallest enclosing circle of the covered points is spanned by one (for duplicates), two, or three of them.
+    // Candidates are evaluated as they are generated, only circles smaller than the best one so far need to be checked for coverage.
+    let mut best: Option<(BigRational, Circle2D<Point>, Vec<usize>)> = None;
+    let mut consider = |squared_radius: BigRational, circle: Circle2D<Point>| {
+        if best
+            .as_ref()
+            .is_some_and(|(best_squared_radius, _, _)| squared_radius >= *best_squared_radius)
+        {
+            return;
+        }
+        let covered_indices = covered(&circle);
+        if covered_indices.len() >= k {
+            best = Some((squared_radius, circle, covered_indices));
+        }
+    };
+    for i in 0..n {
+        consider(BigRational::zero(), Circle2D::One { p: points[i] });
+        for j in i + 1..n {
+            let circle = Circle2D::new(&[points[i], points[j]]);
+            if let Some(squared_radius) = circle.exact_squared_radius() {
+                consider(squared_radius, circle);
+            }
+            for l in j + 1..n {
+                let circle = Circle2D::new(&[points[i], points[j], points[l]]);
+                if let Circle2D::Three { .. } = circle {
+                    // Collinear points do not have a circumcircle.
+                    if let Some(squared_radius) = circle.exact_squared_radius() {
+                        consider(squared_radius, circle);
+                    }
+                }
+            }
+        }
+    }
+    best.map(|(_, circle, covered_indices)| CoveringCircleResult {
+        circle,
+        covered_indices,
+    })
+    .expect("the smallest enclosing circle of all points is a candidate")
+}
+
+/// Takes an iterator over two-dimensional points and returns the smallest circle that encloses at least `k` of the points, together with the indices of the enclosed points.
+///
+/// The smallest enclosing circle of the enclosed points is spanned by one (if they are all duplicates of the same point), two, or three of them, so the problem is solved exactly by enumerating the circles spanned by all single points, pairs, and triples of points, and keeping the one with the smallest (exact) radius that encloses at least `k` points.
+/// This takes `O(n^4)` time in the worst case and `O(n)` memory, so it is intended for moderate input sizes, e.g., a few hundred points.
+/// All enclosed points are reported, which may be more than `k` (e.g., for cocircular points).
+/// The result is a [`CoveringCircleResult`].
+///
+/// # Panics
+///
+/// This function panics if `k` exceeds the number of points.
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::smallest_enclosing_circle_k_of_n;
+///
+/// let points = [[0., 0.], [10., 0.], [10., 1.], [11., 0.], [20., 20.]];
+/// let result = smallest_enclosing_circle_k_of_n(points, 3);
+/// assert_eq!(result.covered_indices, vec![1, 2, 3]);
+/// assert_eq!(result.circle.center(), Some([10.5, 0.5]));
+/// ```
+pub fn smallest_enclosing_circle_k_of_n<Point, C>(
+    points: impl IntoIterator<Item = Point>,
+    k: usize,
+) -> CoveringCircleResult<Point>
+where
+    Point: PartialEq + PointLike<C, 2> + Copy,
+    C: PartialEq + ToExactRational,
+    DefaultInCircle: InCircle<C>,
+    DefaultOrientation: Orientation<C>,
+{
+    smallest_enclosing_circle_k_of_n_with_predicate::<Point, DefaultInCircle, C>(points, k)
+}
+
+/// Takes an iterator over weighted two-dimensional points (pairs of point and positive weight) and returns the center that minimizes the maximum weighted distance `max_i w_i |center - p_i|`, together with this minimal value (the *objective*).
+///
+/// This is the weighted 1-center problem from facility location. For equal weights `w`, the center is the center of the smallest enclosing circle, and the objective is `w` times its radius.
+/// The problem has the same LP-type structure as the smallest enclosing circle, so the same iterative version of Welzl's algorithm is used: the optimum is determined by at most three weighted points, whose weighted distances to the center are all equal (see [`crate::geometry::weighted`]).
+/// The computations are carried out with floating-point arithmetic, so violations are decided up to a small tolerance relative to the objective. The returned objective is the maximum weighted distance of the points to the returned center.
+/// `None` if no points are given.
+///
+/// As for [`smallest_enclosing_circle`], the expected `O(n)` runtime only holds for randomized inputs.
+///
+/// # Panics
+///
+/// This function panics if a weight is not positive.
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::smallest_enclosing_circle_weighted;
+///
+/// // The heavier point pulls the center towards itself.
+/// let (center, objective) = smallest_enclosing_circle_weighted([([0., 0.], 1.), ([4., 0.], 3.), ([3., 0.5], 1.)]).unwrap();
+/// assert_eq!(center, [3., 0.]);
+/// assert_eq!(objective, 3.);
+/// ```
+pub fn smallest_enclosing_circle_weighted<Point, C>(
+    points: impl IntoIterator<Item = (Point, C)>,
+) -> Option<([C; 2], C)>
+where
+    Point: PointLike<C, 2>,
+    C: Real + ConstTwo + TotalOrder,
+{
+    let points: Vec<([C; 2], C)> = points
+        .into_iter()
+        .map(|(point, weight)| {
+            assert!(weight > C::zero(), "weights must be positive");
+            (point.coordinates(), weight)
+        })
+        .collect();
+    // Solutions are kept as (origin, offset, objective), with the center given as offset from one of the spanning points, so the tolerance only depends on the objective, not on the position of the points.
+    let (origin, offset, _) = welzl(
+        points.clone(),
+        Vec::new(),
+        3,
+        |r| match *r {
+            [] => None,
+            [(p, _)] => Some((p, [C::zero(); 2], C::zero())),
+            [a, b] => {
+                let (offset, objective) = weighted_offset2d_two(a, b);
+                Some((a.0, offset, objective))
+            }
+            [a, b, c] => match weighted_offset2d_three(a, b, c) {
+                Some((offset, objective)) => Some((a.0, offset, objective)),
+                None => {
+                    // (Numerically) collinear points: the optimum is spanned by a pair, take the pair center with the smallest maximum weighted distance to all three points.
+                    let weighted_distance = |offset: [C; 2], (p, w): ([C; 2], C)| {
+                        w * ((p[0] - a.0[0]) - offset[0]).hypot((p[1] - a.0[1]) - offset[1])
+                    };
+                    [(a, b), (b, c), (a, c)]
+                        .map(|(u, v)| {
+                            let (offset, _) = weighted_offset2d_two(u, v);
+                            let offset = [0, 1].map(|i| (u.0[i] - a.0[i]) + offset[i]);
+                            let objective = [a, b, c]
+                                .into_iter()
+                                .map(|q| weighted_distance(offset, q))
+                                .fold(C::zero(), C::max);
+                            (a.0, offset, objective)
+                        })
+                        .into_iter()
+                        .min_by(|s, t| s.2.total_cmp(&t.2))
+                }
+            },
+            _ => unreachable!(),
+        },
+        |solution, (p, w)| match solution {
+            Some((origin, offset, objective)) => {
+                let [dx, dy] = [0, 1].map(|i| (p[i] - origin[i]) - offset[i]);
+                let squared = *w * *w * (dx * dx + dy * dy);
+                let tolerance = C::epsilon() * C::from(1024).unwrap();
+                squared > *objective * *objective * (C::one() + tolerance)
+            }
+            None => true,
+        },
+    )?;
+    let center = [origin[0] + offset[0], origin[1] + offset[1]];
+    // The objective is evaluated at the (rounded) center, so that both are consistent.
+    let objective = points
+        .iter()
+        .map(|(p, w)| *w * (p[0] - center[0]).hypot(p[1] - center[1]))
+        .fold(C::zero(), C::max);
+    Some((center, objective))
+}
+
+/// See [`minimum_width_annulus`]. Additionally, supports custom [`InCircle`] and [`Orientation`] predicates.
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::algorithm::minimum_width_annulus_with_predicate;
+/// use smallest_enclosing_circle::predicates::in_circle::DefaultInCircle;
+/// use smallest_enclosing_circle::predicates::orientation::DefaultOrientation;
+///
+/// let annulus = minimum_width_annulus_with_predicate::<_, DefaultInCircle, DefaultOrientation, _>([[1., 0.], [0., 1.], [-1., 0.], [0., -1.]]).unwrap();
+/// assert_eq!(annulus.width(), 0.);
+/// ```
+pub fn minimum_width_annulus_with_predicate<Point, InCirclePredicate, OrientationPredicate, C>(
+    points: impl IntoIterator<Item = Point>,
+) -> Option<Annulus<C>>
+where
+    Point: PartialEq + PointLike<C, 2> + Copy,
+    InCirclePredicate: InCircle<C>,
+    OrientationPredicate: Orientation<C>,
+    C: Real + ConstTwo + Finite + TotalOrder,
+    DefaultOrientationArea: OrientationArea<C>,
+{
+    let mut unique: Vec<Point> = Vec::new();
+    for point in points {
+        if !unique.contains(&point) {
+            unique.push(point);
+        }
+    }
+    let points = unique;
+    let n = points.len();
+    match n {
+        0 => return None,
+        1 => return Annulus::around(points[0].coordinates(), &points),
+        2 => {
+            let [a, b] = [points[0].coordinates(), points[1].coordinates()];
+            let center = [(a[0] + b[0]) / C::TWO, (a[1] + b[1]) / C::TWO];
+            return Annulus::around(center, &points);
+        }
+        _ => {}
+    }
+
+    // Candidate centers are computed on coordinates scaled into [-1, 1], so that squared coordinates neither overflow nor underflow.
+    let scale = points
+        .iter()
+        .flat_map(|p| p.coordinates())
+        .map(|c| c.abs())
+        .fold(C::zero(), C::max);
+    let scaled: Vec<[C; 2]> = points
+        .iter()
+        .map(|p| p.coordinates().map(|c| c / scale))
+        .collect();
+    let unscale = |center: [C; 2]| center.map(|c| c * scale);
+
+    // Candidate centers: vertices of the nearest-point Voronoi diagram (circumcenters of empty circles), vertices of the farthest-point Voronoi diagram (circumcenters of enclosing circles), and intersections of the edges of both diagrams (bisectors).
+    let mut centers = Vec::new();
+    let mut nearest_edges = BTreeSet::new();
+    let mut farthest_edges = BTreeSet::new();
+    for i in 0..n {
+        for j in i + 1..n {
+            for k in j + 1..n {
+                let [a, b, c] = [points[i], points[j], points[k]];
+                if OrientationPredicate::orientation(&a, &b, &c) == OrientationState::Collinear {
+                    continue;
+                }
+                let circle = Circle2D::new_with_predicate::<OrientationPredicate, C>(&[a, b, c]);
+                let (mut empty, mut enclosing) = (true, true);
+                for point in &points {
+                    match circle.locate_with_predicate::<Point, InCirclePredicate, C>(point) {
+                        Some(InCircleState::Inside) => empty = false,
+                        Some(InCircleState::Outside) => enclosing = false,
+                        _ => {}
+                    }
+                    if !empty && !enclosing {
+                        break;
+                    }
+                }
+                let edges = [(i, j), (j, k), (i, k)];
+                if empty {
+                    nearest_edges.extend(edges);
+                }
+                if enclosing {
+                    farthest_edges.extend(edges);
+                }
+                if empty || enclosing {
+                    let triangle = [scaled[i], scaled[j], scaled[k]];
+                    let circle = Circle2D::new_with_predicate::<OrientationPredicate, C>(&triangle);
+                    centers.extend(circle.center().map(unscale));
+                }
+            }
+        }
+    }
+    if centers.is_empty() {
+        // All points are collinear: the width approaches zero for centers that move to infinity, but no annulus attains it.
+        return None;
+    }
+    let bisector = |(i, j): (usize, usize)| {
+        let [a, b] = [scaled[i], scaled[j]];
+        let normal = [b[0] - a[0], b[1] - a[1]];
+        let offset = (b[0] * b[0] + b[1] * b[1] - a[0] * a[0] - a[1] * a[1]) / C::TWO;
+        (normal, offset)
+    };
+    for outer in &farthest_edges {
+        let (m, s) = bisector(*outer);
+        for inner in &nearest_edges {
+            let (l, t) = bisector(*inner);
+            let determinant = m[0] * l[1] - m[1] * l[0];
+            if determinant != C::zero() {
+                centers.push(unscale([
+                    (s * l[1] - t * m[1]) / determinant,
+                    (m[0] * t - l[0] * s) / determinant,
+                ]));
+            }
+        }
+    }
+    // Nearly parallel bisectors yield non-finite candidates, which are skipped.
+    centers
+        .into_iter()
+        .filter(|center| center.iter().all(Finite::is_finite))
+        .filter_map(|center| Annulus::around(center, &points))
+        .filter(|annulus| Finite::is_finite(&annulus.width()))
+        .min_by(|a, b| a.width().total_cmp(&b.width()))
+}
+
+/// Takes an iterator over two-dimensional points and returns the annulus of minimum width (the region between two concentric circles) that contains all points. `None` if no points are given, if there are at least three distinct points and all of them are collinear, or if no candidate center can be computed with finite coordinates (for coordinates close to the limits of the floating-point type).
+///
+/// This is the roundness measure of the minimum zone method (e.g., ISO 12181): the width of the annulus is the roundness deviation of the measured points.
+/// For three or more distinct collinear points, no such annulus exists, as the width approaches zero for centers that move to infinity.
+/// One or two distinct points are contained by an annulus of width zero, which is centered at the point, or at the midpoint of both points, respectively.
+///
+/// The optimal center is either a vertex of the nearest-point Voronoi diagram, a vertex of the farthest-point Voronoi diagram, or an intersection of edges of both diagrams.
+/// The combinatorial decisions, i.e., which triples of points span an empty circle (nearest-point Delaunay triangles) or an enclosing circle (farthest-point Delaunay triangles), are made with the exact [`InCircle`] and [`Orientation`] predicates.
+/// The candidate centers and the radii are then computed with floating-point arithmetic, and the candidate with the smallest width is returned.
+/// The triangles are found by brute force, which takes `O(n^4)` time in the worst case (considerably less in practice, as most triples are rejected early), so this is intended for moderate input sizes, e.g., a few hundred measured points.
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::minimum_width_annulus;
+///
+/// // Points on two concentric circles of radius 1 and 2.
+/// let points = [[1., 0.], [0., 2.], [-1., 0.], [0., -2.], [2., 0.]];
+/// let annulus = minimum_width_annulus(points).unwrap();
+/// assert!(annulus.width() <= 1.);
+///
+/// assert_eq!(minimum_width_annulus([[0., 0.], [1., 1.], [2., 2.]]), None);
+/// ```
+pub fn minimum_width_annulus<Point, C>(
+    points: impl IntoIterator<Item = Point>,
+) -> Option<Annulus<C>>
+where
+    Point: PartialEq + PointLike<C, 2> + Copy,
+    C: Real + ConstTwo + Finite + TotalOrder,
+    DefaultInCircle: InCircle<C>,
+    DefaultOrientation: Orientation<C>,
+    DefaultOrientationArea: OrientationArea<C>,
+{
+    minimum_width_annulus_with_predicate::<Point, DefaultInCircle, DefaultOrientation, C>(points)
+}
+
+/// Takes an iterator over two-dimensional points and returns the center and radius of a circle that encloses all points, and whose radius is at most `(1 + epsilon)` times the radius of the smallest enclosing circle.
+///
+/// This trades exactness for a deterministic runtime of `O(n / epsilon)`, i.e., linear in the number of points for a fixed `epsilon`, without any randomization.
+/// The algorithm maintains a small subset of the points (a *core set*), starting with the first point. In each iteration, the point farthest from the center of the smallest enclosing circle of the core set is determined in a single pass over all points.
+/// If its distance exceeds `(1 + epsilon)` times the radius of that circle, the point is added to the core set, otherwise the circle, enlarged to the farthest point, is returned. This takes `O(1 / epsilon)` iterations, and usually only a handful in the plane.
+/// The returned radius is the distance to the farthest point, rounded upwards by two units in the last place, so that the circle encloses all points despite rounding errors.
+/// `None` if no points are given.
+///
+/// For inputs in random order, the exact (expected `O(n)`) [`smallest_enclosing_circle_randomized`] is comparably fast. This function is preferable if the input order is adversarial (e.g., sorted) and cannot be shuffled, or if a deterministic bound on the number of passes over the points is required.
+///
+/// The algorithm is described in the following work:
+///
+/// Bădoiu, M., & Clarkson, K. L. (2003). Smaller core-sets for balls.
+/// In Proceedings of the fourteenth annual ACM-SIAM symposium on Discrete algorithms (pp. 801-802).
+///
+/// # Panics
+///
+/// This function panics if `epsilon` is NaN, infinite, or not positive. Values below the precision of the coordinate type are raised to 16 units in the last place, as rounding errors dominate then.
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::smallest_enclosing_circle_approximate;
+///
+/// // Input: Four corner points of square box of unit size
+/// let (center, radius) = smallest_enclosing_circle_approximate([[0., 0.], [1., 0.], [1., 1.], [0., 1.]], 0.01).unwrap();
+/// assert!(radius <= 1.01 * f64::sqrt(2.) / 2.);
+/// assert!((center[0] - 0.5).abs() < 0.01 && (center[1] - 0.5).abs() < 0.01);
+/// ```
+pub fn smallest_enclosing_circle_approximate<Point, C>(
+    points: impl IntoIterator<Item = Point>,
+    epsilon: C,
+) -> Option<([C; 2], C)>
+where
+    Point: PointLike<C, 2>,
+    C: Real + ConstTwo + Finite,
+    DefaultInCircle: InCircle<C>,
+    DefaultOrientation: Orientation<C>,
+    DefaultOrientationArea: OrientationArea<C>,
+{
+    assert!(
+        epsilon > C::zero() && Finite::is_finite(&epsilon),
+        "epsilon must be positive and finite"
+    );
+    let epsilon = epsilon.max(C::epsilon() * C::from(16).unwrap());
+    let points: Vec<[C; 2]> = points.into_iter().map(|p| p.coordinates()).collect();
+    let farthest = |center: [C; 2]| {
+        points
+            .iter()
+            .map(|p| (p, (p[0] - center[0]).hypot(p[1] - center[1])))
+            .fold(
+                None,
+                |farthest: Option<(&[C; 2], C)>, (p, d)| match farthest {
+                    Some((_, max)) if max >= d => farthest,
+                    _ => Some((p, d)),
+                },
+            )
+    };
+
+    let mut core = Vec::from([*points.first()?]);
+    loop {
+        let circle = smallest_enclosing_circle(core.iter().copied());
+        let (center, core_radius) = match circle {
+            Circle2D::One { p } => (p, C::zero()),
+            _ => (circle.center()?, circle.radius()?),
+        };
+        let (q, distance) = farthest(center)?;
+        // Points that are already in the core set are only farther than the radius due to rounding errors.
+        if distance <= core_radius * (C::one() + epsilon) || core.contains(q) {
+            return Some((
+                center,
+                distance * (C::one() + C::epsilon() * (C::one() + C::one())),
+            ));
+        }
+        core.push(*q);
+    }
+}
+
+/// See [`smallest_enclosing_circle_with_predicate`]. This is the recursive version, implemented for educational purposes only. You should prefer [`smallest_enclosing_circle_with_predicate`].
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::algorithm::smallest_enclosing_circle_recursive_with_predicate;
+/// use smallest_enclosing_circle::predicates::in_circle::DefaultInCircle;
+///
+/// // Input: Four corner points of square box of unit size
+/// let circle = smallest_enclosing_circle_recursive_with_predicate::<_, DefaultInCircle, _>([[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
+/// assert_eq!(circle.center(), Some([0.5, 0.5]));
+/// assert_eq!(circle.radius(), Some(f64::sqrt(2.) / 2.));
+/// ```
+pub fn smallest_enclosing_circle_recursive_with_predicate<Point, InCirclePredicate, C>(
+    points: impl IntoIterator<Item = Point>,
+) -> Circle2D<Point>
+where
+    Point: PartialEq + PointLike<C, 2> + Copy,
+    InCirclePredicate: InCircle<C>,
+    C: PartialEq,
+    DefaultOrientation: Orientation<C>,
+{
+    fn recursion<Point, InCirclePredicate, C>(p: &[Point], r: &[Point]) -> Circle2D<Point>
+    where
+        Point: PartialEq + PointLike<C, 2> + Copy,
+        InCirclePredicate: InCircle<C>,
+        C: PartialEq,
+        DefaultOrientation: Orientation<C>,
+    {
+        if p.is_empty() || r.len() == 3 {
+            Circle2D::new(r)
+        } else {
+            let remainder = &mut p.to_vec();
+            let element = remainder.pop().unwrap();
+            let mut circle = recursion::<Point, InCirclePredicate, C>(remainder, r);
+            if !circle.contains_with_predicate::<Point, InCirclePredicate, C>(&element) {
+                let x = &mut r.to_vec();
+                x.push(element);
+                circle = recursion::<Point, InCirclePredicate, C>(remainder, x);
+            }
+            circle
+        }
+    }
+
+    recursion::<Point, InCirclePredicate, C>(&points.into_iter().collect::<Vec<_>>(), &[])
+}
+
+/// See [`smallest_enclosing_circle`]. This is the recursive version, implemented for educational purposes only. You should prefer [`smallest_enclosing_circle`].
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::algorithm::smallest_enclosing_circle_recursive;
+///
+/// // Input: Four corner points of square box of unit size
+/// let circle = smallest_enclosing_circle_recursive([[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
+/// assert_eq!(circle.center(), Some([0.5, 0.5]));
+/// assert_eq!(circle.radius(), Some(f64::sqrt(2.) / 2.));
+/// ```
+pub fn smallest_enclosing_circle_recursive<Point, C>(
+    points: impl IntoIterator<Item = Point>,
+) -> Circle2D<Point>
+where
+    Point: PartialEq + PointLike<C, 2> + Copy,
+    C: PartialEq,
+    DefaultInCircle: InCircle<C>,
+    DefaultOrientation: Orientation<C>,
+{
+    smallest_enclosing_circle_recursive_with_predicate::<Point, DefaultInCircle, C>(points)
+}
+
+/// See [`smallest_enclosing_circle_of_circles`]. Additionally, supports a custom [`DiskInCircle`] predicate.
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::algorithm::smallest_enclosing_circle_of_circles_with_predicate;
+/// use smallest_enclosing_circle::disk::Disk;
+/// use smallest_enclosing_circle::predicates::disk_in_circle::DefaultDiskInCircle;
+///
+/// let circle = smallest_enclosing_circle_of_circles_with_predicate::<_, DefaultDiskInCircle, _>([([0., 0.], 1.), ([4., 0.], 1.), ([2., 0.], 1.)]);
+/// assert_eq!(circle.disk(), Some(Disk::new([2., 0.], 3.)));
+/// ```
+pub fn smallest_enclosing_circle_of_circles_with_predicate<D, DiskInCirclePredicate, C>(
+    disks: impl IntoIterator<Item = D>,
+) -> DiskCircle2D<D>
+where
+    D: DiskLike<C> + Copy,
+    DiskInCirclePredicate: DiskInCircle<C>,
+    C: Real + ConstTwo + TotalOrder,
+{
+    let disks: Vec<D> = disks.into_iter().collect();
+    assert!(
+        disks.iter().all(|disk| disk.radius() >= C::zero()),
+        "radii must be non-negative"
+    );
+    let (circle, _) = welzl(
+        disks,
+        Vec::new(),
+        3,
+        |r| {
+            let circle = DiskCircle2D::new(r);
+            (
+                circle,
+                circle.disk_with_predicate::<DiskInCirclePredicate, C>(),
+            )
+        },
+        |(_, disk): &(DiskCircle2D<D>, Option<Disk<C>>), element| match disk {
+            Some(disk) => {
+                DiskInCirclePredicate::disk_in_circle(&disk.center, disk.radius, element)
+                    == InCircleState::Outside
+            }
+            None => true,
+        },
+    );
+    circle
+}
+
+/// Takes an iterator over disks (e.g., `([f64; 2], f64)` center-radius pairs, or any type implementing [`DiskLike`]) and returns the smallest circle that encloses all disks.
+///
+/// This is the generalization of [`smallest_enclosing_circle`] from points to disks, e.g., to build bounding volumes of circular colliders. It runs the same iterative version of Welzl's algorithm, but the circle is spanned by up to three disks that touch it from the inside.
+/// For three disks, the circle is a solution of Apollonius' problem (see [`crate::geometry::apollonius`]).
+/// Radii must be non-negative, disks with radius zero are points.
+/// In contrast to the point version, the circles spanned by disks are subject to rounding errors, so containment is decided up to a small tolerance (see [`DefaultDiskInCircle`]).
+/// The result is a [`DiskCircle2D`] enum, use [`DiskCircle2D::disk`] to obtain center and radius.
+///
+/// As for points, the expected `O(n)` runtime only holds for randomized inputs.
+///
+/// # Panics
+///
+/// This function panics if a radius is negative (or NaN).
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::smallest_enclosing_circle_of_circles;
+///
+/// let circle = smallest_enclosing_circle_of_circles([([0., 0.], 1.), ([4., 0.], 1.), ([2., 0.], 1.), ([2., 1.], 0.5)]);
+/// assert_eq!(circle.center(), Some([2., 0.]));
+/// assert_eq!(circle.radius(), Some(3.));
+/// ```
+pub fn smallest_enclosing_circle_of_circles<D, C>(
+    disks: impl IntoIterator<Item = D>,
+) -> DiskCircle2D<D>
+where
+    D: DiskLike<C> + Copy,
+    C: Real + ConstTwo + TotalOrder,
+    DefaultDiskInCircle: DiskInCircle<C>,
+{
+    smallest_enclosing_circle_of_circles_with_predicate::<D, DefaultDiskInCircle, C>(disks)
+}
+
+/// See [`smallest_enclosing_circle_of_circles_indexed`]. Additionally, supports a custom [`DiskInCircle`] predicate.
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::algorithm::smallest_enclosing_circle_of_circles_indexed_with_predicate;
+/// use smallest_enclosing_circle::predicates::disk_in_circle::DefaultDiskInCircle;
+///
+/// let result = smallest_enclosing_circle_of_circles_indexed_with_predicate::<_, DefaultDiskInCircle, _>([([0., 0.], 1.), ([2., 0.], 1.), ([4., 0.], 1.)]);
+/// let mut support_indices = result.support_indices.clone();
+/// support_indices.sort();
+/// assert_eq!(support_indices, vec![0, 2]);
+/// ```
+pub fn smallest_enclosing_circle_of_circles_indexed_with_predicate<D, DiskInCirclePredicate, C>(
+    disks: impl IntoIterator<Item = D>,
+) -> EnclosingCircleOfCirclesResult<D>
+where
+    D: DiskLike<C> + Copy,
+    DiskInCirclePredicate: DiskInCircle<C>,
+    C: Real + ConstTwo + TotalOrder,
+{
+    let disks: Vec<D> = disks.into_iter().collect();
+    let indexed = disks.iter().enumerate().map(|(index, point)| Indexed {
+        index,
+        point: *point,
+    });
+    let mut support_indices = Vec::new();
+    let circle =
+        smallest_enclosing_circle_of_circles_with_predicate::<_, DiskInCirclePredicate, C>(indexed)
+            .map(|element| {
+                support_indices.push(element.index);
+                element.point
+            });
+    let tight_indices = match circle.disk_with_predicate::<DiskInCirclePredicate, C>() {
+        Some(disk) => (0..disks.len())
+            .filter(|i| {
+                DiskInCirclePredicate::disk_in_circle(&disk.center, disk.radius, &disks[*i])
+                    == InCircleState::On
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+    EnclosingCircleOfCirclesResult {
+        circle,
+        support_indices,
+        tight_indices,
+    }
 }
 
-/// Takes an iterator over two-dimensional points and returns the smallest circle that encloses all points.
+/// Same as [`smallest_enclosing_circle_of_circles`], but additionally returns the indices of the spanning disks within the input.
 ///
-/// Iterative version of Welzl's algorithm, which was originally formulated as recursive algorithm.
-/// The expected input is an of [f64; 2] coordinate pairs with actual numbers (no NaNs or Infinites). Duplicates are allowed.
-/// Note that the original algorithm is based on randomizing the order of input points.
-/// This is omitted in this crate, however randomization can be done by the caller in advance.
-/// The advantage over the recursive algorithm is that large problem sizes do not run into call stack problems.
-/// The result is a [`Circle2D`] enum.
-/// 
-/// Welzl's algorithm solves this problem in expected `O(n)` runtime. Please note that this only holds for randomized inputs (i.e., you may want to shuffle your input stream in advance).
+/// This covers points with individual uncertainty radii (additively weighted points): each input is a point plus its radius, which must be fully enclosed.
+/// The spanning disks touch the resulting circle, but further disks may touch it as well (e.g., for symmetric inputs or duplicates). All inputs whose uncertainty disks touch the circle (i.e., that are *tight*) are reported separately, as decided by the [`DiskInCircle`] predicate.
+/// The result is an [`EnclosingCircleOfCirclesResult`].
 ///
-/// The implementation is based on the following work:
+/// # Panics
 ///
-/// Welzl, E. (1991). Smallest enclosing disks (balls and ellipsoids).
-/// In New results and new trends in computer science (pp. 359-370).
-/// Springer, Berlin, Heidelberg.
+/// This function panics if a radius is negative (or NaN).
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::smallest_enclosing_circle_of_circles_indexed;
+///
+/// // Positions with uncertainty radii.
+/// let result = smallest_enclosing_circle_of_circles_indexed([([0., 0.], 0.5), ([3., 0.], 1.5), ([1., 1.], 0.1)]);
+/// assert_eq!(result.circle.radius(), Some(2.5));
+/// assert_eq!(result.support_indices.len(), 2);
+/// assert_eq!(result.tight_indices, vec![0, 1]);
+/// ```
+pub fn smallest_enclosing_circle_of_circles_indexed<D, C>(
+    disks: impl IntoIterator<Item = D>,
+) -> EnclosingCircleOfCirclesResult<D>
+where
+    D: DiskLike<C> + Copy,
+    C: Real + ConstTwo + TotalOrder,
+    DefaultDiskInCircle: DiskInCircle<C>,
+{
+    smallest_enclosing_circle_of_circles_indexed_with_predicate::<D, DefaultDiskInCircle, C>(disks)
+}
+
+/// Takes an iterator over `N`-dimensional points (e.g., `[f64; 3]`) and returns the smallest ball that encloses all points.
+///
+/// This is the generalization of [`smallest_enclosing_circle`] to arbitrary dimensions, e.g., to compute bounding spheres of 3D point clouds. It runs the same iterative version of Welzl's algorithm, so large problem sizes do not run into call stack problems, but the ball is spanned by up to `N + 1` points.
+/// The spanning ball is computed with floating-point arithmetic, so containment is decided up to a small relative tolerance (see [`Ball::contains`]).
+/// The result is a [`Ball`], use [`Ball::center`] and [`Ball::radius`] to obtain the actual ball.
+///
+/// As for circles, the expected `O(n)` runtime (for fixed `N`) only holds for randomized inputs, see [`smallest_enclosing_ball_randomized`].
+/// Degenerate inputs (e.g., collinear or coplanar points in 3D) may lead to affinely dependent spanning points. For those, the balls spanned by all `2^(N + 1)` subsets are computed whenever such a basis is evaluated, so the cost grows exponentially with `N`.
+///
+/// # Panics
 ///
+/// This function panics if more than 63 affinely dependent points span a ball, see [`Ball::center_and_squared_radius`].
 ///
 /// # Examples
 ///
 /// ```
-/// use smallest_enclosing_circle::smallest_enclosing_circle;
+/// use smallest_enclosing_circle::algorithm::smallest_enclosing_ball;
 ///
-/// // Input: Four corner points of square box of unit size
-/// let circle = smallest_enclosing_circle([[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
-/// assert_eq!(circle.center(), Some([0.5, 0.5]));
-/// assert_eq!(circle.radius(), Some(f64::sqrt(2.) / 2.));
+/// // Input: Eight corner points of a cube of unit size
+/// let points = [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [1., 1., 0.], [0., 0., 1.], [1., 0., 1.], [0., 1., 1.], [1., 1., 1.]];
+/// let ball = smallest_enclosing_ball(points);
+/// let center = ball.center().unwrap();
+/// assert!(center.iter().all(|c: &f64| (c - 0.5).abs() < 1e-12));
+/// assert!((ball.radius().unwrap() - f64::sqrt(3.) / 2.).abs() < 1e-12);
 /// ```
-pub fn smallest_enclosing_circle<Point>(points: impl IntoIterator<Item = Point>) -> Circle2D<Point>
+pub fn smallest_enclosing_ball<Point, C, const N: usize>(
+    points: impl IntoIterator<Item = Point>,
+) -> Ball<Point, N>
 where
-    Point: PartialEq + PointLike<f64, 2> + Copy,
+    Point: PointLike<C, N> + Copy,
+    C: Real + TotalOrder,
 {
-    smallest_enclosing_circle_with_predicate::<Point, DefaultInCircle>(points)
+    let (ball, _) = welzl(
+        points.into_iter().collect(),
+        Vec::new(),
+        N + 1,
+        |r| {
+            let ball = Ball::new(r);
+            let sphere = ball.sphere();
+            (ball, sphere)
+        },
+        |(_, sphere): &(Ball<Point, N>, Option<Sphere<C, N>>), element| match sphere {
+            Some(sphere) => !sphere.encloses(&element.coordinates()),
+            None => true,
+        },
+    );
+    ball
 }
 
-/// See [`smallest_enclosing_circle_with_predicate`]. This is the recursive version, implemented for educational purposes only. You should prefer [`smallest_enclosing_circle_with_predicate`].
+/// Same as [`smallest_enclosing_ball`], but shuffles the input points before running Welzl's algorithm, see [`smallest_enclosing_circle_randomized`].
 ///
 /// # Examples
 ///
 /// ```
-/// use smallest_enclosing_circle::algorithm::smallest_enclosing_circle_recursive_with_predicate;
-/// use smallest_enclosing_circle::predicates::in_circle::DefaultInCircle;
+/// use smallest_enclosing_circle::algorithm::smallest_enclosing_ball_randomized;
 ///
-/// // Input: Four corner points of square box of unit size
-/// let circle = smallest_enclosing_circle_recursive_with_predicate::<_, DefaultInCircle>([[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
-/// assert_eq!(circle.center(), Some([0.5, 0.5]));
-/// assert_eq!(circle.radius(), Some(f64::sqrt(2.) / 2.));
+/// let ball = smallest_enclosing_ball_randomized([[-1., 0., 0.], [1., 0., 0.], [0., 0.5, 0.5]], 42);
+/// assert_eq!(ball.center(), Some([0., 0., 0.]));
+/// assert_eq!(ball.radius(), Some(1.));
 /// ```
-pub fn smallest_enclosing_circle_recursive_with_predicate<Point, InCirclePredicate>(
+pub fn smallest_enclosing_ball_randomized<Point, C, const N: usize>(
     points: impl IntoIterator<Item = Point>,
-) -> Circle2D<Point>
+    seed: u64,
+) -> Ball<Point, N>
 where
-    Point: PartialEq + PointLike<f64, 2> + Copy,
-    InCirclePredicate: InCircle<f64>,
+    Point: PointLike<C, N> + Copy,
+    C: Real + TotalOrder,
 {
-    fn recursion<Point, InCirclePredicate>(p: &Vec<Point>, r: &Vec<Point>) -> Circle2D<Point>
-    where
-        Point: PartialEq + PointLike<f64, 2> + Copy,
-        InCirclePredicate: InCircle<f64>,
-    {
-        if p.len() == 0 || r.len() == 3 {
-            Circle2D::new(&r)
-        } else {
-            let remainder = &mut p.to_vec();
-            let element = remainder.pop().unwrap();
-            let mut circle = recursion::<Point, InCirclePredicate>(remainder, r);
-            if !circle.contains_with_predicate::<Point, InCirclePredicate>(&element) {
-                let x = &mut r.to_vec();
-                x.push(element);
-                circle = recursion::<Point, InCirclePredicate>(remainder, x);
-            }
-            circle
-        }
-    }
+    let mut p: Vec<Point> = points.into_iter().collect();
+    SplitMix64::new(seed).shuffle(&mut p);
+    smallest_enclosing_ball(p)
+}
 
-    recursion::<Point, InCirclePredicate>(&points.into_iter().collect(), &Vec::new())
+/// Takes an iterator over two-dimensional points and returns the ellipse of minimum area that encloses all points.
+///
+/// Welzl's algorithm applies to ellipses as well (see the title of the original work), it runs the same iterative version as [`smallest_enclosing_circle`], but the ellipse is spanned by up to five points.
+/// The spanning ellipses are computed with `f64` arithmetic (other coordinate types are not supported), so containment is decided up to a small relative tolerance (see [`Ellipse::contains`]).
+/// The result is an [`Ellipse2D`], use [`Ellipse2D::ellipse`] to obtain center, semi-axes, and rotation.
+///
+/// As for circles, the expected `O(n)` runtime only holds for randomized inputs, see [`smallest_enclosing_ellipse_randomized`].
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::algorithm::smallest_enclosing_ellipse;
+///
+/// // Input: Four corner points of a rectangle, and its center
+/// let ellipse = smallest_enclosing_ellipse([[-2., -1.], [2., -1.], [2., 1.], [-2., 1.], [0., 0.]]).ellipse().unwrap();
+/// assert!((ellipse.semi_axes[0] - 2. * f64::sqrt(2.)).abs() < 1e-9);
+/// assert!((ellipse.semi_axes[1] - f64::sqrt(2.)).abs() < 1e-9);
+/// assert!(ellipse.rotation.abs() < 1e-6);
+/// ```
+pub fn smallest_enclosing_ellipse<Point>(
+    points: impl IntoIterator<Item = Point>,
+) -> Ellipse2D<Point>
+where
+    Point: PointLike<f64, 2> + Copy,
+{
+    let (ellipse, _) = welzl(
+        points.into_iter().collect(),
+        Vec::new(),
+        5,
+        |r| {
+            let ellipse = Ellipse2D::new(r);
+            let shape = ellipse.ellipse();
+            (ellipse, shape)
+        },
+        |(_, shape): &(Ellipse2D<Point>, Option<Ellipse>), element| match shape {
+            Some(shape) => !shape.contains(element),
+            None => true,
+        },
+    );
+    ellipse
 }
 
-/// See [`smallest_enclosing_circle`]. This is the recursive version, implemented for educational purposes only. You should prefer [`smallest_enclosing_circle`].
+/// Same as [`smallest_enclosing_ellipse`], but shuffles the input points before running Welzl's algorithm, see [`smallest_enclosing_circle_randomized`].
 ///
 /// # Examples
 ///
 /// ```
-/// use smallest_enclosing_circle::algorithm::smallest_enclosing_circle_recursive;
+/// use smallest_enclosing_circle::algorithm::smallest_enclosing_ellipse_randomized;
 ///
-/// // Input: Four corner points of square box of unit size
-/// let circle = smallest_enclosing_circle_recursive([[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
-/// assert_eq!(circle.center(), Some([0.5, 0.5]));
-/// assert_eq!(circle.radius(), Some(f64::sqrt(2.) / 2.));
+/// let ellipse = smallest_enclosing_ellipse_randomized([[-1., 0.], [1., 0.], [0., 0.]], 42).ellipse().unwrap();
+/// assert_eq!(ellipse.semi_axes, [1., 0.]);
 /// ```
-pub fn smallest_enclosing_circle_recursive<Point>(
+pub fn smallest_enclosing_ellipse_randomized<Point>(
     points: impl IntoIterator<Item = Point>,
-) -> Circle2D<Point>
+    seed: u64,
+) -> Ellipse2D<Point>
 where
-    Point: PartialEq + PointLike<f64, 2> + Copy,
+    Point: PointLike<f64, 2> + Copy,
 {
-    smallest_enclosing_circle_recursive_with_predicate::<Point, DefaultInCircle>(points)
+    let mut p: Vec<Point> = points.into_iter().collect();
+    SplitMix64::new(seed).shuffle(&mut p);
+    smallest_enclosing_ellipse(p)
 }
 
 #[cfg(test)]
@@ -179,7 +1412,7 @@ mod tests {
 
     macro_rules! assert_equals_circles {
         ($circle1: expr, $circle2: expr) => {
-            $circle1.equals($circle2)
+            assert!($circle1.equals($circle2))
         };
     }
 
@@ -191,7 +1424,7 @@ mod tests {
                     let points: Vec<[f64; 2]> = $points.to_vec();
                     let n = points.into_iter().count();
                     Itertools::permutations($points.into_iter(), n).for_each(|permutation: Vec<[f64; 2]>| {
-                        let result = [<$function>]::<[f64; 2]>(
+                        let result = [<$function>]::<[f64; 2], f64>(
                             permutation,
                         );
                         assert_equals_circles!(result, &Circle2D::<[f64; 2]>::new(&$expected_circle_points));
@@ -343,6 +1576,831 @@ mod tests {
         };
     }
 
+    fn smallest_enclosing_circle_randomized_seeded<Point, C>(
+        points: impl IntoIterator<Item = Point>,
+    ) -> Circle2D<Point>
+    where
+        Point: PartialEq + PointLike<C, 2> + Copy,
+        C: PartialEq,
+        DefaultInCircle: InCircle<C>,
+        DefaultOrientation: Orientation<C>,
+    {
+        smallest_enclosing_circle_randomized(points, 42)
+    }
+
     test_function!(smallest_enclosing_circle);
     test_function!(smallest_enclosing_circle_recursive);
+    test_function!(smallest_enclosing_circle_randomized_seeded);
+    test_function!(smallest_enclosing_circle_move_to_front);
+
+    #[test]
+    fn randomized_is_reproducible() {
+        let points: Vec<[f64; 2]> = (0..1000)
+            .map(|i| [i as f64, ((i * 7919) % 1000) as f64])
+            .collect();
+        let circle1 = smallest_enclosing_circle_randomized(points.clone(), 7);
+        let circle2 = smallest_enclosing_circle_randomized(points.clone(), 7);
+        assert_eq!(circle1, circle2);
+        assert!(circle1.equals(&smallest_enclosing_circle(points)));
+    }
+
+    mod indexed {
+        use super::*;
+
+        #[test]
+        fn empty() {
+            let result = smallest_enclosing_circle_indexed(Vec::<[f64; 2]>::new());
+            assert_eq!(result.circle, Circle2D::None);
+            assert!(result.support_indices.is_empty());
+        }
+
+        #[test]
+        fn single() {
+            let result = smallest_enclosing_circle_indexed([[3., 4.]]);
+            assert_eq!(result.circle, Circle2D::One { p: [3., 4.] });
+            assert_eq!(result.support_indices, vec![0]);
+        }
+
+        #[test]
+        fn indices_match_spanning_points() {
+            let points = [
+                [0., 0.],
+                [0.5, 0.2],
+                [1., 0.],
+                [0.2, 0.7],
+                [0., 1.],
+                [0.5, 0.5],
+            ];
+            let result = smallest_enclosing_circle_indexed(points);
+            assert!(result.circle.equals(&smallest_enclosing_circle(points)));
+            let spanning = match result.circle {
+                Circle2D::Two { a, b } => vec![a, b],
+                Circle2D::Three { a, b, c, .. } => vec![a, b, c],
+                _ => unreachable!(),
+            };
+            let indexed: Vec<[f64; 2]> =
+                result.support_indices.iter().map(|&i| points[i]).collect();
+            assert_eq!(spanning, indexed);
+        }
+
+        #[test]
+        fn duplicates() {
+            let result = smallest_enclosing_circle_indexed([[1., 0.], [0., 0.], [1., 0.]]);
+            assert!(result.circle.equals(&Circle2D::new(&[[0., 0.], [1., 0.]])));
+            assert_eq!(result.support_indices.len(), 2);
+            assert!(result.support_indices.contains(&1));
+        }
+    }
+
+    #[test]
+    fn f32_coordinates() {
+        let points: Vec<[f32; 2]> = vec![[0., 0.], [1., 0.], [1., 1.], [0., 1.], [0.5, 0.25]];
+        let circle = smallest_enclosing_circle(points.clone());
+        assert_eq!(circle.center(), Some([0.5f32, 0.5]));
+        assert_eq!(circle.radius(), Some(f32::sqrt(2.) / 2.));
+        assert!(points.iter().all(|p| circle.contains(p)));
+        assert!(circle.equals(&smallest_enclosing_circle_move_to_front(points)));
+    }
+
+    #[test]
+    fn integer_coordinates() {
+        let m = i64::MAX / 2;
+        let points: Vec<[i64; 2]> = vec![[-m, -m], [m, -m], [m, m], [-m, m], [0, 0], [m - 1, 1]];
+        let circle = smallest_enclosing_circle(points.clone());
+        assert!(circle.equals(&Circle2D::new(&[[-m, -m], [m, m]])));
+        assert!(points.iter().all(|p| circle.contains(p)));
+        assert_eq!(
+            circle.exact_squared_radius(),
+            Some(num::BigRational::from_integer(num::BigInt::from(m) * m * 2))
+        );
+    }
+
+    #[test]
+    fn move_to_front_sorted() {
+        let points: Vec<[f64; 2]> = (0..10000)
+            .map(|i| [(i % 100) as f64, (i / 100) as f64])
+            .collect();
+        let circle = smallest_enclosing_circle_move_to_front(points.clone());
+        assert!(circle.equals(&Circle2D::new(&[[0., 0.], [99., 99.]])));
+        assert!(points.iter().all(|p| circle.contains(p)));
+    }
+
+    mod of_circles {
+        use super::*;
+
+        fn assert_encloses(circle: &DiskCircle2D<([f64; 2], f64)>, disks: &[([f64; 2], f64)]) {
+            let disk = circle.disk().unwrap();
+            for (center, radius) in disks {
+                let distance = (center[0] - disk.center[0]).hypot(center[1] - disk.center[1]);
+                assert!(
+                    distance + radius <= disk.radius * (1. + 1e-9),
+                    "{disk:?} {center:?} {radius}"
+                );
+            }
+        }
+
+        #[test]
+        fn empty() {
+            assert_eq!(
+                smallest_enclosing_circle_of_circles(Vec::<([f64; 2], f64)>::new()),
+                DiskCircle2D::None
+            );
+        }
+
+        #[test]
+        fn single() {
+            let circle = smallest_enclosing_circle_of_circles([([1., 2.], 3.)]);
+            assert_eq!(circle.disk(), Some(Disk::new([1., 2.], 3.)));
+        }
+
+        #[test]
+        #[should_panic(expected = "radii must be non-negative")]
+        fn negative_radius() {
+            smallest_enclosing_circle_of_circles([([0., 0.], 1.), ([2., 0.], -1.)]);
+        }
+
+        #[test]
+        fn nested() {
+            let disks = [([0., 0.], 1.), ([0., 0.], 5.), ([1., 1.], 2.)];
+            Itertools::permutations(disks.into_iter(), 3).for_each(|permutation| {
+                let circle = smallest_enclosing_circle_of_circles(permutation);
+                assert_eq!(circle.disk(), Some(Disk::new([0., 0.], 5.)));
+            });
+        }
+
+        #[test]
+        fn three_touching() {
+            let disks = [
+                ([-2., 0.], 1.),
+                ([2., 0.], 1.),
+                ([0., 2.5], 1.),
+                ([0., 0.], 1.),
+            ];
+            Itertools::permutations(disks.into_iter(), 4).for_each(|permutation| {
+                let circle = smallest_enclosing_circle_of_circles(permutation);
+                assert!(matches!(circle, DiskCircle2D::Three { .. }));
+                assert_encloses(&circle, &disks);
+            });
+        }
+
+        #[test]
+        fn points() {
+            let points: Vec<[f64; 2]> = (0..200)
+                .map(|i| [((i * 7919) % 211) as f64, ((i * 104729) % 199) as f64])
+                .collect();
+            let expected = smallest_enclosing_circle(points.clone());
+            let circle = smallest_enclosing_circle_of_circles(points.iter().map(|p| (*p, 0.)));
+            assert!((circle.radius().unwrap() - expected.radius().unwrap()).abs() < 1e-9);
+        }
+
+        #[test]
+        fn random() {
+            let mut rng = SplitMix64::new(3);
+            let mut random = |scale: f64| rng.next_f64() * scale;
+            for _ in 0..50 {
+                let disks: Vec<([f64; 2], f64)> = (0..50)
+                    .map(|_| ([random(100.) - 50., random(100.) - 50.], random(10.)))
+                    .collect();
+                let circle = smallest_enclosing_circle_of_circles(disks.clone());
+                assert_encloses(&circle, &disks);
+                // Minimality: the circle is spanned by touching disks, and cannot be shrunk by the alternative bases.
+                let spanning: Vec<([f64; 2], f64)> = match circle {
+                    DiskCircle2D::Two { a, b } => vec![a, b],
+                    DiskCircle2D::Three { a, b, c } => vec![a, b, c],
+                    _ => unreachable!(),
+                };
+                let disk = circle.disk().unwrap();
+                for (center, radius) in spanning {
+                    let distance = (center[0] - disk.center[0]).hypot(center[1] - disk.center[1]);
+                    assert!((distance + radius - disk.radius).abs() < 1e-9);
+                }
+            }
+        }
+
+        #[test]
+        fn f32() {
+            let circle = smallest_enclosing_circle_of_circles([([0f32, 0.], 1.), ([4., 0.], 1.)]);
+            assert_eq!(circle.radius(), Some(3.));
+        }
+
+        #[test]
+        fn indexed() {
+            let mut rng = SplitMix64::new(25);
+            let disks: Vec<([f64; 2], f64)> = (0..300)
+                .map(|_| ([rng.next_f64() * 10., rng.next_f64() * 10.], rng.next_f64()))
+                .collect();
+            let result = smallest_enclosing_circle_of_circles_indexed(disks.clone());
+            assert_eq!(
+                result.circle,
+                smallest_enclosing_circle_of_circles(disks.clone())
+            );
+            assert_encloses(&result.circle, &disks);
+            let disk = result.circle.disk().unwrap();
+            for index in &result.support_indices {
+                let (center, radius) = disks[*index];
+                let distance = (center[0] - disk.center[0]).hypot(center[1] - disk.center[1]);
+                assert!((distance + radius - disk.radius).abs() < 1e-9);
+            }
+            let spanning: Vec<([f64; 2], f64)> =
+                result.support_indices.iter().map(|i| disks[*i]).collect();
+            assert_eq!(DiskCircle2D::new(&spanning), result.circle);
+            assert!(result
+                .support_indices
+                .iter()
+                .all(|i| result.tight_indices.contains(i)));
+        }
+
+        #[test]
+        fn tight() {
+            let disks = [
+                ([2., 0.], 1.),
+                ([-2., 0.], 1.),
+                ([0., 2.], 1.),
+                ([0., -2.], 1.),
+                ([2., 0.], 1.),
+                ([0., 0.], 1.),
+            ];
+            let result = smallest_enclosing_circle_of_circles_indexed(disks);
+            assert_eq!(result.circle.radius(), Some(3.));
+            assert_eq!(result.support_indices.len(), 2);
+            assert_eq!(result.tight_indices, vec![0, 1, 2, 3, 4]);
+        }
+    }
+
+    mod ball {
+        use super::*;
+
+        fn random_points<const N: usize>(seed: u64, n: usize) -> Vec<[f64; N]> {
+            let mut rng = SplitMix64::new(seed);
+            (0..n)
+                .map(|_| std::array::from_fn(|_| rng.next_f64()))
+                .collect()
+        }
+
+        #[test]
+        fn empty() {
+            let ball = smallest_enclosing_ball::<[f64; 3], f64, 3>(Vec::new());
+            assert!(ball.support().is_empty());
+            assert_eq!(ball.center(), None);
+        }
+
+        #[test]
+        fn plane() {
+            let points: Vec<[f64; 2]> = random_points(1, 500);
+            let ball = smallest_enclosing_ball(points.clone());
+            let circle = smallest_enclosing_circle(points);
+            assert!((ball.radius().unwrap() - circle.radius().unwrap()).abs() < 1e-12);
+        }
+
+        #[test]
+        fn three_dimensions() {
+            let points: Vec<[f64; 3]> = random_points(2, 1000);
+            let ball = smallest_enclosing_ball_randomized(points.clone(), 42);
+            assert!(points.iter().all(|p| ball.contains(p)));
+            let (center, squared_radius) = ball.center_and_squared_radius().unwrap();
+            for p in ball.support() {
+                let d: f64 = (0..3).map(|i| (p[i] - center[i]).powi(2)).sum();
+                assert!((d - squared_radius).abs() < 1e-12);
+            }
+            assert!(ball.support().len() >= 2);
+        }
+
+        #[test]
+        fn five_dimensions() {
+            let points: Vec<[f64; 5]> = random_points(3, 300);
+            let ball = smallest_enclosing_ball(points.clone());
+            assert!(points.iter().all(|p| ball.contains(p)));
+        }
+
+        #[test]
+        fn degenerate() {
+            let points: Vec<[f64; 3]> = (0..100).map(|i| [i as f64, 2. * i as f64, 0.]).collect();
+            let ball = smallest_enclosing_ball(points.clone());
+            assert!(points.iter().all(|p| ball.contains(p)));
+            let expected = (99f64 * 99. + 198. * 198.).sqrt() / 2.;
+            assert!((ball.radius().unwrap() - expected).abs() < 1e-9);
+        }
+
+        #[test]
+        fn translated() {
+            // The tolerance of the containment checks must not grow with the distance to the origin.
+            let points: Vec<[f64; 3]> = random_points(5, 1000);
+            let moved: Vec<[f64; 3]> = points.iter().map(|p| p.map(|x| x + 1e6)).collect();
+            let ball = smallest_enclosing_ball(points);
+            let moved_ball = smallest_enclosing_ball(moved.clone());
+            assert!((moved_ball.radius().unwrap() - ball.radius().unwrap()).abs() < 1e-8);
+            let (center, radius) = (moved_ball.center().unwrap(), moved_ball.radius().unwrap());
+            for p in moved {
+                let d: f64 = (0..3).map(|i| (p[i] - center[i]).powi(2)).sum();
+                assert!(d.sqrt() <= radius + 1e-8);
+            }
+        }
+
+        #[test]
+        fn large() {
+            let points: Vec<[f64; 3]> = random_points(4, 200000);
+            let ball = smallest_enclosing_ball_randomized(points.clone(), 7);
+            assert!(points.iter().all(|p| ball.contains(p)));
+        }
+    }
+
+    mod ellipse {
+        use super::*;
+
+        /// Brute force: the smallest ellipse spanned by any subset of at most five points that encloses all points.
+        fn brute_force(points: &[[f64; 2]]) -> Ellipse {
+            (1..=5)
+                .flat_map(|k| points.iter().copied().combinations(k))
+                .filter_map(|subset| Ellipse2D::new(&subset).ellipse())
+                .filter(|e| points.iter().all(|p| e.contains(p)))
+                .min_by(|a, b| a.area().total_cmp(&b.area()))
+                .unwrap()
+        }
+
+        #[test]
+        fn empty() {
+            assert_eq!(
+                smallest_enclosing_ellipse(Vec::<[f64; 2]>::new()).ellipse(),
+                None
+            );
+        }
+
+        #[test]
+        fn collinear() {
+            let ellipse = smallest_enclosing_ellipse([[0., 0.], [1., 1.], [3., 3.], [2., 2.]])
+                .ellipse()
+                .unwrap();
+            assert_eq!(ellipse.center, [1.5, 1.5]);
+            assert_eq!(ellipse.semi_axes[1], 0.);
+        }
+
+        #[test]
+        fn random() {
+            let mut rng = SplitMix64::new(11);
+            for _ in 0..20 {
+                let points: Vec<[f64; 2]> = (0..8)
+                    .map(|_| [rng.next_f64() * 4., rng.next_f64()])
+                    .collect();
+                let ellipse = smallest_enclosing_ellipse(points.clone())
+                    .ellipse()
+                    .unwrap();
+                assert!(points.iter().all(|p| ellipse.contains(p)));
+                let expected = brute_force(&points);
+                assert!((ellipse.area() - expected.area()).abs() < 1e-6 * expected.area());
+            }
+        }
+
+        #[test]
+        fn many_points() {
+            let mut rng = SplitMix64::new(5);
+            let mut random = || rng.next_f64() * 2. - 1.;
+            let points: Vec<[f64; 2]> = (0..10000)
+                .map(|_| [random(), random()])
+                .filter(|[x, y]| (x * x) / 4. + y * y <= 0.25)
+                .map(|[x, y]| [x + y, y - x])
+                .collect();
+            let ellipse = smallest_enclosing_ellipse_randomized(points.clone(), 1)
+                .ellipse()
+                .unwrap();
+            assert!(points.iter().all(|p| ellipse.contains(p)));
+            let circle = smallest_enclosing_circle(points);
+            assert!(ellipse.area() < std::f64::consts::PI * circle.radius().unwrap().powi(2));
+        }
+    }
+
+    mod approximate {
+        use super::*;
+
+        #[test]
+        fn empty() {
+            assert_eq!(
+                smallest_enclosing_circle_approximate(Vec::<[f64; 2]>::new(), 0.1),
+                None
+            );
+        }
+
+        #[test]
+        fn single() {
+            assert_eq!(
+                smallest_enclosing_circle_approximate([[1., 2.], [1., 2.]], 0.1),
+                Some(([1., 2.], 0.))
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "epsilon must be positive and finite")]
+        fn zero_epsilon() {
+            smallest_enclosing_circle_approximate([[1., 2.]], 0.);
+        }
+
+        #[test]
+        #[should_panic(expected = "epsilon must be positive and finite")]
+        fn nan_epsilon() {
+            smallest_enclosing_circle_approximate([[1., 2.]], f64::NAN);
+        }
+
+        #[test]
+        fn tiny_epsilon() {
+            // The circle is exact up to rounding errors, the number of iterations stays small.
+            let points = [[0., 0.], [4., 0.], [1., 3.], [2., -1.], [3., 2.5]];
+            let (_, radius) = smallest_enclosing_circle_approximate(points, 1e-300).unwrap();
+            let optimal = smallest_enclosing_circle(points).radius().unwrap();
+            assert!(radius >= optimal && radius <= optimal * (1. + 1e-12));
+        }
+
+        #[test]
+        fn sorted() {
+            // A monotone input, which is the worst case for the order of Welzl's algorithm.
+            let points: Vec<[f64; 2]> = (0..100_000)
+                .map(|i| [i as f64, (i as f64 * 0.37).sin()])
+                .collect();
+            let (_, radius) = smallest_enclosing_circle_approximate(points.clone(), 1e-3).unwrap();
+            let optimal = smallest_enclosing_circle_randomized(points, 1)
+                .radius()
+                .unwrap();
+            assert!(radius >= optimal && radius <= optimal * (1. + 1e-3));
+        }
+
+        #[test]
+        fn guarantee() {
+            let mut rng = SplitMix64::new(9);
+            for epsilon in [0.5, 0.1, 0.01] {
+                let points: Vec<[f64; 2]> = (0..2000)
+                    .map(|_| [rng.next_f64() * 3., rng.next_f64()])
+                    .collect();
+                let (center, radius) =
+                    smallest_enclosing_circle_approximate(points.clone(), epsilon).unwrap();
+                let optimal = smallest_enclosing_circle(points.clone()).radius().unwrap();
+                assert!(radius >= optimal);
+                assert!(radius <= (1. + epsilon) * optimal);
+                assert!(points
+                    .iter()
+                    .all(|p| (p[0] - center[0]).hypot(p[1] - center[1]) <= radius));
+            }
+        }
+
+        #[test]
+        fn f32() {
+            let (_, radius) =
+                smallest_enclosing_circle_approximate([[0f32, 0.], [2., 0.], [1., 0.5]], 0.1)
+                    .unwrap();
+            assert!((1. ..=1.1).contains(&radius));
+        }
+    }
+
+    mod outliers {
+        use super::*;
+
+        /// Brute force: the smallest enclosing circle over all subsets that exclude at most `k` points.
+        fn brute_force(points: &[[i64; 2]], k: usize) -> BigRational {
+            (points.len().saturating_sub(k)..=points.len())
+                .flat_map(|size| (0..points.len()).combinations(size))
+                .map(|subset| {
+                    smallest_enclosing_circle(subset.iter().map(|i| points[*i]))
+                        .exact_squared_radius()
+                        .unwrap_or_else(BigRational::zero)
+                })
+                .min()
+                .unwrap()
+        }
+
+        #[test]
+        fn empty() {
+            let result = smallest_enclosing_circle_with_outliers(Vec::<[f64; 2]>::new(), 2);
+            assert_eq!(result.circle, Circle2D::None);
+            assert!(result.outlier_indices.is_empty());
+        }
+
+        #[test]
+        fn no_outliers() {
+            let points = [[0., 0.], [3., 1.], [1., 2.], [2., -1.]];
+            let result = smallest_enclosing_circle_with_outliers(points, 0);
+            assert!(result.circle.equals(&smallest_enclosing_circle(points)));
+            assert!(result.outlier_indices.is_empty());
+        }
+
+        #[test]
+        fn all_outliers() {
+            let result = smallest_enclosing_circle_with_outliers([[0., 0.], [1., 0.], [2., 0.]], 5);
+            assert!(matches!(result.circle, Circle2D::One { .. }));
+            assert_eq!(result.outlier_indices.len(), 2);
+        }
+
+        #[test]
+        fn cocircular() {
+            // Removing a spanning point of a square does not shrink the circle, so there are no outliers.
+            let result =
+                smallest_enclosing_circle_with_outliers([[0, 0], [2, 0], [2, 2], [0, 2]], 1);
+            assert!(result.circle.equals(&Circle2D::new(&[[0, 0], [2, 2]])));
+            assert!(result.outlier_indices.is_empty());
+        }
+
+        #[test]
+        fn random() {
+            let mut rng = SplitMix64::new(13);
+            for _ in 0..20 {
+                let points: Vec<[i64; 2]> = (0..9)
+                    .map(|_| [rng.next_below(100) as i64, rng.next_below(100) as i64])
+                    .collect();
+                for k in 0..4 {
+                    let result = smallest_enclosing_circle_with_outliers(points.clone(), k);
+                    let squared_radius = result
+                        .circle
+                        .exact_squared_radius()
+                        .unwrap_or_else(BigRational::zero);
+                    assert_eq!(squared_radius, brute_force(&points, k));
+                    assert!(result.outlier_indices.len() <= k);
+                    for (i, p) in points.iter().enumerate() {
+                        assert_eq!(
+                            result.circle.contains(p),
+                            !result.outlier_indices.contains(&i)
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    mod k_of_n {
+        use super::*;
+
+        /// Brute force: the smallest enclosing circle over all subsets of `k` points.
+        fn brute_force(points: &[[i64; 2]], k: usize) -> BigRational {
+            (0..points.len())
+                .combinations(k)
+                .map(|subset| {
+                    smallest_enclosing_circle(subset.iter().map(|i| points[*i]))
+                        .exact_squared_radius()
+                        .unwrap_or_else(BigRational::zero)
+                })
+                .min()
+                .unwrap()
+        }
+
+        #[test]
+        fn trivial() {
+            let points = [[0., 0.], [1., 0.]];
+            assert_eq!(
+                smallest_enclosing_circle_k_of_n(points, 0).circle,
+                Circle2D::None
+            );
+            assert_eq!(
+                smallest_enclosing_circle_k_of_n(points, 1).covered_indices,
+                vec![0]
+            );
+            assert_eq!(
+                smallest_enclosing_circle_k_of_n(Vec::<[f64; 2]>::new(), 0).circle,
+                Circle2D::None
+            );
+        }
+
+        #[test]
+        fn duplicates() {
+            let result =
+                smallest_enclosing_circle_k_of_n([[5., 5.], [0., 0.], [5., 5.], [5., 5.]], 3);
+            assert_eq!(result.circle, Circle2D::One { p: [5., 5.] });
+            assert_eq!(result.covered_indices, vec![0, 2, 3]);
+        }
+
+        #[test]
+        #[should_panic(expected = "cannot enclose 3 points")]
+        fn too_few_points() {
+            smallest_enclosing_circle_k_of_n([[0., 0.], [1., 0.]], 3);
+        }
+
+        #[test]
+        fn all() {
+            let points = [[0., 0.], [3., 1.], [1., 2.], [2., -1.]];
+            let result = smallest_enclosing_circle_k_of_n(points, 4);
+            assert!(result.circle.equals(&smallest_enclosing_circle(points)));
+        }
+
+        #[test]
+        fn random() {
+            let mut rng = SplitMix64::new(15);
+            for _ in 0..10 {
+                let points: Vec<[i64; 2]> = (0..10)
+                    .map(|_| [rng.next_below(100) as i64, rng.next_below(100) as i64])
+                    .collect();
+                for k in 2..=10 {
+                    let result = smallest_enclosing_circle_k_of_n(points.clone(), k);
+                    let squared_radius = result
+                        .circle
+                        .exact_squared_radius()
+                        .unwrap_or_else(BigRational::zero);
+                    assert_eq!(squared_radius, brute_force(&points, k));
+                    assert!(result.covered_indices.len() >= k);
+                    for (i, p) in points.iter().enumerate() {
+                        assert_eq!(
+                            result.circle.contains(p),
+                            result.covered_indices.contains(&i)
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    mod weighted {
+        use super::*;
+
+        use crate::geometry::weighted::weighted_center2d_two;
+
+        fn objective(center: [f64; 2], points: &[([f64; 2], f64)]) -> f64 {
+            points
+                .iter()
+                .map(|(p, w)| w * (p[0] - center[0]).hypot(p[1] - center[1]))
+                .fold(0., f64::max)
+        }
+
+        #[test]
+        fn empty() {
+            assert_eq!(
+                smallest_enclosing_circle_weighted(Vec::<([f64; 2], f64)>::new()),
+                None
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "weights must be positive")]
+        fn zero_weight() {
+            smallest_enclosing_circle_weighted([([0., 0.], 0.)]);
+        }
+
+        #[test]
+        fn equal_weights() {
+            let points = [[0., 0.], [3., 1.], [1., 2.], [2., -1.], [1., 1.]];
+            let (center, value) =
+                smallest_enclosing_circle_weighted(points.iter().map(|p| (*p, 2.))).unwrap();
+            let circle = smallest_enclosing_circle(points);
+            let expected = circle.center().unwrap();
+            assert!(
+                (center[0] - expected[0]).abs() < 1e-12 && (center[1] - expected[1]).abs() < 1e-12
+            );
+            assert!((value - 2. * circle.radius().unwrap()).abs() < 1e-12);
+        }
+
+        #[test]
+        fn collinear() {
+            let points = [([0., 0.], 1.), ([1., 0.], 10.), ([2., 0.], 1.)];
+            let (center, value) = smallest_enclosing_circle_weighted(points).unwrap();
+            assert!((value - objective(center, &points)).abs() < 1e-12);
+            assert!((value - 1.).abs() < 1e-12);
+        }
+
+        #[test]
+        fn collinear_triples() {
+            let mut rng = SplitMix64::new(17);
+            for _ in 0..100 {
+                let points: Vec<([f64; 2], f64)> = (0..3)
+                    .map(|_| ([rng.next_f64(), 0.], 0.1 + rng.next_f64()))
+                    .collect();
+                let (center, value) = smallest_enclosing_circle_weighted(points.clone()).unwrap();
+                assert_eq!(value, objective(center, &points));
+                // On a line, the optimum is attained at the center of one of the pairs.
+                let optimum = points
+                    .iter()
+                    .tuple_combinations()
+                    .map(|(a, b)| objective(weighted_center2d_two(*a, *b).0, &points))
+                    .fold(f64::INFINITY, f64::min);
+                assert!((value - optimum).abs() < 1e-12);
+            }
+        }
+
+        #[test]
+        fn translated() {
+            // The tolerance of the violation checks must not grow with the distance to the origin.
+            let mut rng = SplitMix64::new(13);
+            let points: Vec<([f64; 2], f64)> = (0..500)
+                .map(|_| ([rng.next_f64(), rng.next_f64()], 0.1 + rng.next_f64()))
+                .collect();
+            let moved: Vec<([f64; 2], f64)> = points
+                .iter()
+                .map(|(p, w)| (p.map(|x| x + 1e6), *w))
+                .collect();
+            let (_, value) = smallest_enclosing_circle_weighted(points).unwrap();
+            let (center, moved_value) = smallest_enclosing_circle_weighted(moved.clone()).unwrap();
+            assert_eq!(moved_value, objective(center, &moved));
+            assert!((moved_value - value).abs() < 1e-8);
+        }
+
+        #[test]
+        fn random() {
+            let mut rng = SplitMix64::new(19);
+            for _ in 0..20 {
+                let points: Vec<([f64; 2], f64)> = (0..200)
+                    .map(|_| ([rng.next_f64(), rng.next_f64()], 0.1 + rng.next_f64()))
+                    .collect();
+                let (center, value) = smallest_enclosing_circle_weighted(points.clone()).unwrap();
+                assert!((value - objective(center, &points)).abs() < 1e-9);
+                // No small step improves the objective.
+                for angle in (0..16).map(|i| i as f64 * std::f64::consts::PI / 8.) {
+                    let moved = [
+                        center[0] + 1e-6 * angle.cos(),
+                        center[1] + 1e-6 * angle.sin(),
+                    ];
+                    assert!(objective(moved, &points) >= value - 1e-12);
+                }
+            }
+        }
+    }
+
+    mod annulus {
+        use super::*;
+
+        /// Brute force: the best annulus centered at any circumcenter of three points, or at any intersection of two bisectors.
+        fn brute_force(points: &[[f64; 2]]) -> f64 {
+            let mut centers: Vec<[f64; 2]> = points
+                .iter()
+                .copied()
+                .combinations(3)
+                .filter_map(|t| Circle2D::try_new(&t).ok().and_then(|c| c.center()))
+                .collect();
+            let bisectors: Vec<([f64; 2], f64)> = points
+                .iter()
+                .combinations(2)
+                .map(|p| {
+                    let normal = [p[1][0] - p[0][0], p[1][1] - p[0][1]];
+                    let offset = (p[1][0] * p[1][0] + p[1][1] * p[1][1]
+                        - p[0][0] * p[0][0]
+                        - p[0][1] * p[0][1])
+                        / 2.;
+                    (normal, offset)
+                })
+                .collect();
+            for ((m, s), (l, t)) in bisectors.iter().tuple_combinations() {
+                let determinant = m[0] * l[1] - m[1] * l[0];
+                if determinant != 0. {
+                    centers.push([
+                        (s * l[1] - t * m[1]) / determinant,
+                        (m[0] * t - l[0] * s) / determinant,
+                    ]);
+                }
+            }
+            centers
+                .into_iter()
+                .map(|center| Annulus::around(center, points).unwrap().width())
+                .fold(f64::INFINITY, f64::min)
+        }
+
+        #[test]
+        fn degenerate() {
+            assert_eq!(minimum_width_annulus(Vec::<[f64; 2]>::new()), None);
+            assert_eq!(
+                minimum_width_annulus([[1., 2.], [1., 2.]]),
+                Some(Annulus::new([1., 2.], 0., 0.))
+            );
+            assert_eq!(
+                minimum_width_annulus([[0., 0.], [2., 0.]]),
+                Some(Annulus::new([1., 0.], 1., 1.))
+            );
+            assert_eq!(
+                minimum_width_annulus([[0., 0.], [1., 0.], [3., 0.], [1., 0.]]),
+                None
+            );
+        }
+
+        #[test]
+        fn cocircular() {
+            let points = [[0., 0.], [2., 0.], [2., 2.], [0., 2.], [0., 0.]];
+            let annulus = minimum_width_annulus(points).unwrap();
+            assert_eq!(
+                annulus,
+                Annulus::new([1., 1.], f64::sqrt(2.), f64::sqrt(2.))
+            );
+        }
+
+        #[test]
+        fn random() {
+            let mut rng = SplitMix64::new(27);
+            for _ in 0..20 {
+                // Noisy samples of a circle, as produced by a roundness measurement.
+                let points: Vec<[f64; 2]> = (0..9)
+                    .map(|_| {
+                        let angle = rng.next_f64() * std::f64::consts::TAU;
+                        let radius = 5. + rng.next_f64() * 0.5;
+                        [3. + radius * angle.cos(), -1. + radius * angle.sin()]
+                    })
+                    .collect();
+                let annulus = minimum_width_annulus(points.clone()).unwrap();
+                assert!((annulus.width() - brute_force(&points)).abs() < 1e-9);
+                assert_eq!(Annulus::around(annulus.center, &points), Some(annulus));
+            }
+        }
+
+        #[test]
+        fn huge_coordinates() {
+            // Squared coordinates overflow or underflow, the candidates are computed on scaled coordinates.
+            for scale in [1e-160, 1e150, 1e160, 1e300] {
+                let points = [[1., 0.], [0., 1.1], [-1., 0.], [0., -1.], [0.7, 0.7]]
+                    .map(|p| p.map(|c| c * scale));
+                let annulus = minimum_width_annulus(points).unwrap();
+                assert!(annulus.width().is_finite());
+                for [x, y] in points {
+                    let distance = (x - annulus.center[0]).hypot(y - annulus.center[1]);
+                    assert!(annulus.inner_radius <= distance && distance <= annulus.outer_radius);
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,71 @@
+use num::traits::real::Real;
+
+use crate::geometry::point::PointLike;
+
+/// Represents the result of [`crate::algorithm::minimum_width_annulus`], the region between two concentric circles.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Annulus<C> {
+    /// The common center of both circles.
+    pub center: [C; 2],
+    /// The radius of the inner circle, i.e., the distance of the nearest point to the center.
+    pub inner_radius: C,
+    /// The radius of the outer circle, i.e., the distance of the farthest point to the center.
+    pub outer_radius: C,
+}
+
+impl<C> Annulus<C> {
+    /// Creates a new [`Annulus`] from its center and both radii. The radii are not checked, i.e., the inner radius is expected to be at most the outer radius.
+    pub fn new(center: [C; 2], inner_radius: C, outer_radius: C) -> Self {
+        Annulus {
+            center,
+            inner_radius,
+            outer_radius,
+        }
+    }
+
+    /// The width of the annulus, i.e., the difference of the outer and the inner radius. For roundness measurements, this is the roundness deviation.
+    ///
+    /// ```
+    /// use smallest_enclosing_circle::annulus::Annulus;
+    ///
+    /// assert_eq!(Annulus::new([0., 0.], 1.5, 2.).width(), 0.5);
+    /// ```
+    pub fn width(&self) -> C
+    where
+        C: Real,
+    {
+        self.outer_radius - self.inner_radius
+    }
+
+    /// Creates the thinnest annulus with the given center that contains all given points, i.e., the radii are the minimum and maximum distance of the points to the center. `None` if no points are given.
+    pub fn around<P>(center: [C; 2], points: &[P]) -> Option<Self>
+    where
+        P: PointLike<C, 2>,
+        C: Real,
+    {
+        let distance = |p: &P| {
+            let [x, y] = p.coordinates();
+            (x - center[0]).hypot(y - center[1])
+        };
+        let first = distance(points.first()?);
+        let (inner_radius, outer_radius) = points
+            .iter()
+            .skip(1)
+            .map(distance)
+            .fold((first, first), |(min, max), d| (min.min(d), max.max(d)));
+        Some(Annulus::new(center, inner_radius, outer_radius))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn around() {
+        let annulus = Annulus::around([0., 0.], &[[1., 0.], [0., -3.], [2., 0.]]).unwrap();
+        assert_eq!(annulus, Annulus::new([0., 0.], 1., 3.));
+        assert_eq!(annulus.width(), 2.);
+        assert_eq!(Annulus::around([0., 0.], &Vec::<[f64; 2]>::new()), None);
+    }
+}
@@ -0,0 +1,282 @@
+use std::cmp::Ordering;
+
+use num::traits::{float::TotalOrder, real::Real};
+
+use crate::geometry::point::PointLike;
+
+/// Represents the result of [`crate::algorithm::smallest_enclosing_ball`], an `N`-dimensional ball defined by up to `N + 1` points that are located on its boundary (points that *span* the ball).
+///
+/// This is the `N`-dimensional counterpart of [`crate::Circle2D`]. As for circles, only the spanning points are stored, and the center and radius are computed on demand.
+/// In contrast to [`crate::Circle2D`], the computations are carried out with floating-point arithmetic (Gaussian elimination), so containment checks use a small relative tolerance.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Ball<Point, const N: usize> {
+    support: Vec<Point>,
+}
+
+impl<Point, const N: usize> Ball<Point, N> {
+    /// Creates a new [`Ball`] from up to `N + 1` points.
+    ///
+    /// If the points are affinely independent, they all lie on the boundary of the resulting ball. Otherwise (e.g., for three collinear points), the ball is the smallest one spanned by a subset of the points that encloses all of them, see [`Ball::center`].
+    ///
+    /// # Panics
+    ///
+    /// This function panics if more than `N + 1` points are given.
+    pub fn new<C>(points: &[Point]) -> Self
+    where
+        Point: PointLike<C, N> + Copy,
+    {
+        if points.len() > N + 1 {
+            panic!(
+                "a ball in {} dimensions is spanned by at most {} points, but {} points were supplied",
+                N,
+                N + 1,
+                points.len()
+            );
+        }
+        Ball {
+            support: points.to_vec(),
+        }
+    }
+
+    /// The points that span the ball.
+    pub fn support(&self) -> &[Point] {
+        &self.support
+    }
+
+    /// Computes the center and the squared radius of the ball. `None` for a ball without spanning points, a single point yields a ball of radius zero.
+    ///
+    /// For affinely dependent spanning points, the circumspheres of all `2^k - 2` proper subsets of the `k` points are computed, which takes exponential time in the dimension.
+    ///
+    /// ```
+    /// use smallest_enclosing_circle::ball::Ball;
+    ///
+    /// let ball = Ball::new(&[[0., 0., 0.], [2., 0., 0.]]);
+    /// assert_eq!(ball.center_and_squared_radius(), Some(([1., 0., 0.], 1.)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function panics if more than 63 affinely dependent points span the ball, as the subsets of the points cannot be enumerated then.
+    pub fn center_and_squared_radius<C>(&self) -> Option<([C; N], C)>
+    where
+        Point: PointLike<C, N>,
+        C: Real + TotalOrder,
+    {
+        self.sphere().map(|s| (s.center(), s.squared_radius))
+    }
+
+    /// Computes the ball as [`Sphere`], see [`Ball::center_and_squared_radius`].
+    pub(crate) fn sphere<C>(&self) -> Option<Sphere<C, N>>
+    where
+        Point: PointLike<C, N>,
+        C: Real + TotalOrder,
+    {
+        let points: Vec<[C; N]> = self.support.iter().map(|p| p.coordinates()).collect();
+        circumsphere(&points).or_else(|| {
+            // Affinely dependent points: the smallest ball spanned by a subset that encloses all points.
+            let subsets = u32::try_from(points.len())
+                .ok()
+                .and_then(|len| 1usize.checked_shl(len))
+                .expect("the subsets of more than 63 affinely dependent points cannot be enumerated");
+            (1..subsets - 1)
+                .filter_map(|mask| {
+                    let subset: Vec<[C; N]> = (0..points.len())
+                        .filter(|i| mask & (1 << i) != 0)
+                        .map(|i| points[i])
+                        .collect();
+                    circumsphere(&subset)
+                })
+                .filter(|sphere| points.iter().all(|p| sphere.encloses(p)))
+                .min_by(|a, b| a.squared_radius.total_cmp(&b.squared_radius))
+        })
+    }
+
+    /// Computes the center of the ball. `None` for a ball without spanning points. See [`Ball::center_and_squared_radius`].
+    pub fn center<C>(&self) -> Option<[C; N]>
+    where
+        Point: PointLike<C, N>,
+        C: Real + TotalOrder,
+    {
+        self.center_and_squared_radius().map(|b| b.0)
+    }
+
+    /// Computes the radius of the ball. `None` for a ball without spanning points. See [`Ball::center_and_squared_radius`].
+    pub fn radius<C>(&self) -> Option<C>
+    where
+        Point: PointLike<C, N>,
+        C: Real + TotalOrder,
+    {
+        self.center_and_squared_radius().map(|b| b.1.sqrt())
+    }
+
+    /// Checks whether the given point is contained by the ball, up to a small tolerance relative to the squared radius that absorbs the rounding errors in the computed center.
+    pub fn contains<P, C>(&self, point: &P) -> bool
+    where
+        Point: PointLike<C, N>,
+        P: PointLike<C, N>,
+        C: Real + TotalOrder,
+    {
+        match self.sphere() {
+            Some(sphere) => sphere.encloses(&point.coordinates()),
+            None => false,
+        }
+    }
+}
+
+/// A ball given by one of its spanning points (the *origin*), the offset of the center from the origin, and the squared radius.
+///
+/// All distances are measured relative to the origin, so the rounding errors (and thus the tolerance of [`Sphere::encloses`]) only depend on the size of the ball, not on its position.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Sphere<C, const N: usize> {
+    origin: [C; N],
+    offset: [C; N],
+    squared_radius: C,
+}
+
+impl<C: Real, const N: usize> Sphere<C, N> {
+    fn center(&self) -> [C; N] {
+        std::array::from_fn(|i| self.origin[i] + self.offset[i])
+    }
+
+    /// Checks whether `point` lies within the ball (up to a tolerance relative to the squared radius).
+    pub(crate) fn encloses(&self, point: &[C; N]) -> bool {
+        let squared_distance = (0..N).fold(C::zero(), |sum, i| {
+            let d = (point[i] - self.origin[i]) - self.offset[i];
+            sum + d * d
+        });
+        let tolerance = C::epsilon() * C::from(1024).unwrap() * self.squared_radius;
+        squared_distance <= self.squared_radius + tolerance
+    }
+}
+
+/// Computes the smallest ball that has all given points on its boundary. `None` if no points are given or the points are affinely dependent.
+///
+/// The center is `p_0 + sum_i λ_i (p_i - p_0)`, where the coefficients λ solve the linear system `2 (p_i - p_0) · (p_j - p_0) λ_j = |p_i - p_0|^2`.
+fn circumsphere<C: Real + TotalOrder, const N: usize>(points: &[[C; N]]) -> Option<Sphere<C, N>> {
+    let (origin, rest) = points.split_first()?;
+    let vectors: Vec<[C; N]> = rest
+        .iter()
+        .map(|p| std::array::from_fn(|i| p[i] - origin[i]))
+        .collect();
+    let dot = |a: &[C; N], b: &[C; N]| (0..N).fold(C::zero(), |sum, i| sum + a[i] * b[i]);
+    let two = C::one() + C::one();
+    let mut system: Vec<Vec<C>> = vectors
+        .iter()
+        .map(|u| {
+            let mut row: Vec<C> = vectors.iter().map(|v| two * dot(u, v)).collect();
+            row.push(dot(u, u));
+            row
+        })
+        .collect();
+    let lambda = solve(&mut system)?;
+    let offset: [C; N] = std::array::from_fn(|i| {
+        vectors
+            .iter()
+            .zip(&lambda)
+            .fold(C::zero(), |sum, (v, l)| sum + *l * v[i])
+    });
+    Some(Sphere {
+        origin: *origin,
+        offset,
+        squared_radius: dot(&offset, &offset),
+    })
+}
+
+/// Solves the linear system given as augmented matrix, using Gaussian elimination with partial pivoting. `None` if the system is (numerically) singular.
+fn solve<C: Real + TotalOrder>(system: &mut [Vec<C>]) -> Option<Vec<C>> {
+    let n = system.len();
+    let scale = system
+        .iter()
+        .flat_map(|row| row[..n].iter())
+        .fold(C::zero(), |max, v| max.max(v.abs()));
+    let threshold = scale * C::epsilon() * C::from(64).unwrap();
+    for k in 0..n {
+        let pivot = (k..n)
+            .max_by(|&i, &j| system[i][k].abs().total_cmp(&system[j][k].abs()))
+            .unwrap();
+        if system[pivot][k].abs().partial_cmp(&threshold) != Some(Ordering::Greater) {
+            return None;
+        }
+        system.swap(k, pivot);
+        let (upper, lower) = system.split_at_mut(k + 1);
+        let pivot_row = &upper[k];
+        for row in lower {
+            let factor = row[k] / pivot_row[k];
+            for (value, pivot_value) in row[k..].iter_mut().zip(&pivot_row[k..]) {
+                *value = *value - factor * *pivot_value;
+            }
+        }
+    }
+    let mut x = vec![C::zero(); n];
+    for k in (0..n).rev() {
+        let sum = (k + 1..n).fold(system[k][n], |sum, j| sum - system[k][j] * x[j]);
+        x[k] = sum / system[k][k];
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(
+            Ball::<[f64; 3], 3>::new(&[]).center_and_squared_radius(),
+            None
+        );
+    }
+
+    #[test]
+    fn single() {
+        assert_eq!(
+            Ball::new(&[[1., 2., 3.]]).center_and_squared_radius(),
+            Some(([1., 2., 3.], 0.))
+        );
+    }
+
+    #[test]
+    fn tetrahedron() {
+        let ball = Ball::new(&[[1., 1., 1.], [1., -1., -1.], [-1., 1., -1.], [-1., -1., 1.]]);
+        let (center, squared_radius) = ball.center_and_squared_radius().unwrap();
+        assert!(center.iter().all(|c| c.abs() < 1e-12));
+        assert!((squared_radius - 3.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn collinear() {
+        let ball = Ball::new(&[[0., 0., 0.], [1., 0., 0.], [3., 0., 0.]]);
+        assert_eq!(
+            ball.center_and_squared_radius(),
+            Some(([1.5, 0., 0.], 2.25))
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let ball = Ball::new(&[[0., 0., 0.], [2., 0., 0.]]);
+        assert!(ball.contains(&[1., 0.5, 0.5]));
+        assert!(ball.contains(&[1., 1., 0.]));
+        assert!(!ball.contains(&[1., 1., 0.1]));
+        assert!(!Ball::<[f64; 3], 3>::new(&[]).contains(&[0., 0., 0.]));
+    }
+
+    #[test]
+    #[should_panic(expected = "at most 4 points")]
+    fn too_many() {
+        Ball::new(&[[0., 0., 0.]; 5]);
+    }
+
+    #[test]
+    fn four_dimensions() {
+        let ball = Ball::new(&[
+            [1., 0., 0., 0.],
+            [0., 1., 0., 0.],
+            [0., 0., 1., 0.],
+            [0., 0., 0., 1.],
+        ]);
+        let (center, squared_radius) = ball.center_and_squared_radius().unwrap();
+        assert!(center.iter().all(|c| (c - 0.25).abs() < 1e-12));
+        assert!((squared_radius - 0.75).abs() < 1e-12);
+    }
+}
@@ -1,10 +1,21 @@
 use std::fmt::Debug;
 
+use num::{traits::real::Real, BigRational};
+
 use crate::{
-    geometry::{circumcircle::CircumCircle, point::PointLike},
+    error::CircleError,
+    geometry::{
+        circumcircle::{exact_circumcircle2d, exact_circumcircle2d_two, CircumCircle},
+        interval::{EncloseRational, Interval},
+        num::{ConstTwo, Finite, ToExactRational},
+        point::PointLike,
+    },
     predicates::{
         in_circle::{DefaultInCircle, InCircle, InCircleState},
-        orientation::{DefaultOrientation, Orientation, OrientationState},
+        orientation::{
+            DefaultOrientation, DefaultOrientationArea, Orientation, OrientationArea,
+            OrientationState,
+        },
     },
 };
 
@@ -52,23 +63,77 @@ pub enum Circle2D<Point> {
 
 impl<P> Circle2D<P>
 where
-    P: PartialEq + PointLike<f64, 2> + Copy,
+    P: PartialEq + Copy,
 {
     /// Creates a new [`Circle2D`] spanned by 0 to 3 points.
     ///
     /// # Panics
     ///
-    /// Panics if more than 3 points are supplied.
-    pub fn new(points: &[P]) -> Self {
-        Self::new_with_predicate::<DefaultOrientation>(points)
+    /// Panics if more than 3 points are supplied. See [`Circle2D::try_new`] for a non-panicking version.
+    pub fn new<C>(points: &[P]) -> Self
+    where
+        P: PointLike<C, 2>,
+        DefaultOrientation: Orientation<C>,
+    {
+        Self::new_with_predicate::<DefaultOrientation, C>(points)
+    }
+
+    /// Creates a new [`Circle2D`] spanned by 0 to 3 points.
+    ///
+    /// In contrast to [`Circle2D::new`], the points are validated: returns [`CircleError::TooManyPoints`] if more than 3 points are supplied, [`CircleError::NonFinite`] if any coordinate is NaN or infinite, and [`CircleError::Collinear`] if 3 distinct collinear points are supplied.
+    ///
+    /// ```
+    /// use smallest_enclosing_circle::{Circle2D, CircleError};
+    ///
+    /// assert!(Circle2D::try_new(&[[0., 0.], [1., 0.], [1., 1.]]).is_ok());
+    /// assert_eq!(Circle2D::try_new(&[[0., 0.], [1., 0.], [2., 0.]]), Err(CircleError::Collinear));
+    /// assert_eq!(Circle2D::try_new(&[[0., 0.], [1., 0.], [1., 1.], [0., 1.]]), Err(CircleError::TooManyPoints(4)));
+    /// ```
+    pub fn try_new<C>(points: &[P]) -> Result<Self, CircleError>
+    where
+        P: PointLike<C, 2>,
+        C: Finite,
+        DefaultOrientation: Orientation<C>,
+    {
+        Self::try_new_with_predicate::<DefaultOrientation, C>(points)
+    }
+
+    /// See [`Circle2D::try_new`]. Uses a custom [`Orientation`] predicate to determine whether 3 supplied points are in clockwise, counterclockwise, or collinear order.
+    pub fn try_new_with_predicate<O, C>(points: &[P]) -> Result<Self, CircleError>
+    where
+        P: PointLike<C, 2>,
+        C: Finite,
+        O: Orientation<C>,
+    {
+        if points.len() > 3 {
+            return Err(CircleError::TooManyPoints(points.len()));
+        }
+        if points
+            .iter()
+            .any(|p| p.coordinates().iter().any(|x| !x.is_finite()))
+        {
+            return Err(CircleError::NonFinite);
+        }
+        match Self::new_with_predicate::<O, C>(points) {
+            Circle2D::Three { a, b, c, .. }
+                if O::orientation(&a, &b, &c) == OrientationState::Collinear =>
+            {
+                Err(CircleError::Collinear)
+            }
+            circle => Ok(circle),
+        }
     }
 
     /// Creates a new [`Circle2D`] spanned by 0 to 3 points. If 3 points are supplied, uses a custom [`Orientation`] predicate to determine whether they are in clockwise or counterclockwise order.
     ///
     /// # Panics
     ///
-    /// Panics if more than 3 points are supplied.
-    pub fn new_with_predicate<O: Orientation<f64>>(points: &[P]) -> Self {
+    /// Panics if more than 3 points are supplied. See [`Circle2D::try_new_with_predicate`] for a non-panicking version.
+    pub fn new_with_predicate<O, C>(points: &[P]) -> Self
+    where
+        P: PointLike<C, 2>,
+        O: Orientation<C>,
+    {
         match points.len() {
             0 => Circle2D::None,
             1 => Circle2D::One { p: points[0] },
@@ -87,7 +152,7 @@ where
                 let [ab, bc, ca] = [a == b, b == c, c == a];
                 match (ab, bc, ca) {
                     (true, true, true) => Circle2D::One { p: a },
-                    (true, false, false) => Circle2D::Two { a, b },
+                    (true, false, false) => Circle2D::Two { a, b: c },
                     (false, true, false) => Circle2D::Two { a, b },
                     (false, false, true) => Circle2D::Two { a: b, b: c },
                     (false, false, false) => Circle2D::Three {
@@ -102,23 +167,51 @@ where
                     }
                 }
             }
-            _ => {
-                panic!()
+            n => {
+                panic!("{}", CircleError::TooManyPoints(n))
             }
         }
     }
 }
 
-impl<P> Circle2D<P>
-where
-    P: PointLike<f64, 2>,
-{
-    /// For a [`Circle2D`] spanned by 2 points, computes a third (surrogate) point that is used for [`InCircle`] checks. Otherwise `None`.
-    pub fn surrogate(&self) -> Option<[f64; 2]> {
+impl<P> Circle2D<P> {
+    /// Maps the spanning points with the given function, keeping the variant (and orientation) as is. The mapping must not change the geometry of the points.
+    pub(crate) fn map<Q>(self, mut f: impl FnMut(P) -> Q) -> Circle2D<Q> {
+        match self {
+            Circle2D::None => Circle2D::None,
+            Circle2D::One { p } => Circle2D::One { p: f(p) },
+            Circle2D::Two { a, b } => Circle2D::Two { a: f(a), b: f(b) },
+            Circle2D::Three {
+                a,
+                b,
+                c,
+                counter_clockwise,
+            } => Circle2D::Three {
+                a: f(a),
+                b: f(b),
+                c: f(c),
+                counter_clockwise,
+            },
+        }
+    }
+}
+
+impl<P> Circle2D<P> {
+    /// For a [`Circle2D`] spanned by 2 points, computes a third (surrogate) point on the circle, e.g., for [`InCircle::in_circle`] checks. Otherwise `None`.
+    ///
+    /// The surrogate point is subject to rounding errors, so [`InCircle::in_circle`] checks against it are not exact. Use [`InCircle::in_diametral_circle`] instead.
+    #[deprecated(
+        note = "rounded surrogate points make in-circle checks inexact, use `InCircle::in_diametral_circle` instead"
+    )]
+    pub fn surrogate<C>(&self) -> Option<[C; 2]>
+    where
+        P: PointLike<C, 2>,
+        C: Real + ConstTwo,
+    {
         match self {
             Circle2D::Two { a, b } => {
                 let [a, b] = [a.coordinates(), b.coordinates()];
-                let [mx, my] = [(a[0] + b[0]) / 2., (a[1] + b[1]) / 2.];
+                let [mx, my] = [(a[0] + b[0]) / C::TWO, (a[1] + b[1]) / C::TWO];
                 Some([mx - my + a[1], my + mx - a[0]])
             }
             _ => None,
@@ -126,11 +219,13 @@ where
     }
 }
 
-impl<P> CircumCircle<[f64; 2], f64> for Circle2D<P>
+impl<P, C> CircumCircle<[C; 2], C> for Circle2D<P>
 where
-    P: PointLike<f64, 2>,
+    P: PointLike<C, 2>,
+    C: Real + ConstTwo + Finite,
+    DefaultOrientationArea: OrientationArea<C>,
 {
-    fn circumcircle(&self) -> Option<([f64; 2], f64)> {
+    fn circumcircle(&self) -> Option<([C; 2], C)> {
         match self {
             Circle2D::None => None,
             Circle2D::One { .. } => None,
@@ -142,46 +237,192 @@ where
     }
 }
 
-impl<P> Circle2D<P>
-where
-    P: PointLike<f64, 2>,
-{
-    /// Computes the radius of the circle. `None` for degenerate circles spanned by 0 or 1 points. This procedure is not numerically robust.
-    pub fn radius(&self) -> Option<f64> {
-        self.circumcircle().map(|c| c.1)
+impl<P> Circle2D<P> {
+    /// Computes the radius of the circle. `None` for degenerate circles spanned by 0 or 1 points. This procedure is not numerically robust, see [`Circle2D::radius_with_error`] for a guaranteed enclosure.
+    pub fn radius<C>(&self) -> Option<C>
+    where
+        P: PointLike<C, 2>,
+        C: Real + ConstTwo + Finite,
+        DefaultOrientationArea: OrientationArea<C>,
+    {
+        CircumCircle::<[C; 2], C>::circumcircle(self).map(|c| c.1)
     }
 }
 
-impl<P> Circle2D<P>
-where
-    P: PointLike<f64, 2>,
-{
-    /// Computes the center of the circle. `None` for degenerate circles spanned by 0 or 1 points. This procedure is not numerically robust.
-    pub fn center(&self) -> Option<[f64; 2]> {
-        self.circumcircle().map(|c| c.0)
+impl<P> Circle2D<P> {
+    /// Computes the center of the circle. `None` for degenerate circles spanned by 0 or 1 points. This procedure is not numerically robust, see [`Circle2D::center_with_error`] for a guaranteed enclosure.
+    pub fn center<C>(&self) -> Option<[C; 2]>
+    where
+        P: PointLike<C, 2>,
+        C: Real + ConstTwo + Finite,
+        DefaultOrientationArea: OrientationArea<C>,
+    {
+        CircumCircle::<[C; 2], C>::circumcircle(self).map(|c| c.0)
     }
 }
 
-impl<P> Circle2D<P>
-where
-    P: PointLike<f64, 2>,
-{
+impl<P> Circle2D<P> {
+    /// Computes the exact center of the circle, as a pair of arbitrary precision rationals. `None` for degenerate circles spanned by 0 or 1 points (or 3 collinear points), and for non-finite coordinates.
+    ///
+    /// ```
+    /// use num::BigRational;
+    /// use smallest_enclosing_circle::Circle2D;
+    ///
+    /// let circle = Circle2D::new(&[[0i64, 0], [1, 0], [0, 1]]);
+    /// let half = BigRational::new(1.into(), 2.into());
+    /// assert_eq!(circle.exact_center(), Some([half.clone(), half]));
+    /// ```
+    pub fn exact_center<C>(&self) -> Option<[BigRational; 2]>
+    where
+        P: PointLike<C, 2>,
+        C: ToExactRational,
+    {
+        self.exact_circumcircle().map(|c| c.0)
+    }
+
+    /// Computes the exact squared radius of the circle, as an arbitrary precision rational. `None` for degenerate circles spanned by 0 or 1 points (or 3 collinear points), and for non-finite coordinates.
+    ///
+    /// ```
+    /// use num::BigRational;
+    /// use smallest_enclosing_circle::Circle2D;
+    ///
+    /// let circle = Circle2D::new(&[[0i64, 0], [1, 0], [0, 1]]);
+    /// assert_eq!(circle.exact_squared_radius(), Some(BigRational::new(1.into(), 2.into())));
+    /// ```
+    pub fn exact_squared_radius<C>(&self) -> Option<BigRational>
+    where
+        P: PointLike<C, 2>,
+        C: ToExactRational,
+    {
+        self.exact_circumcircle().map(|c| c.1)
+    }
+
+    /// Computes the tightest [`Interval`]s of representable values that contain the exact coordinates of the center. `None` for degenerate circles spanned by 0 or 1 points (or 3 collinear points), and for non-finite coordinates.
+    ///
+    /// In contrast to [`Circle2D::center`], the result is provably correct: the computation is carried out with exact rationals, and only the final result is rounded (downwards and upwards).
+    ///
+    /// ```
+    /// use smallest_enclosing_circle::Circle2D;
+    ///
+    /// let circle = Circle2D::new(&[[0f64, 0.], [1., 0.], [0.1, 0.7]]);
+    /// let [x, y] = circle.center_with_error().unwrap();
+    /// assert!(x.contains(0.5));
+    /// assert!(y.lower < y.upper && y.upper == y.lower.next_up());
+    /// ```
+    pub fn center_with_error<C>(&self) -> Option<[Interval<C>; 2]>
+    where
+        P: PointLike<C, 2>,
+        C: ToExactRational + EncloseRational,
+    {
+        self.exact_circumcircle()
+            .map(|c| c.0.each_ref().map(C::enclose))
+    }
+
+    /// Computes the tightest [`Interval`] of representable values that contains the exact radius. `None` for degenerate circles spanned by 0 or 1 points (or 3 collinear points), and for non-finite coordinates.
+    ///
+    /// In contrast to [`Circle2D::radius`], the result is provably correct: the computation is carried out with exact rationals, and only the final square root is enclosed by rounding downwards and upwards.
+    ///
+    /// ```
+    /// use smallest_enclosing_circle::Circle2D;
+    ///
+    /// let circle = Circle2D::new(&[[0., 0.], [1., 0.], [1., 1.]]);
+    /// let radius = circle.radius_with_error().unwrap();
+    /// assert!(radius.contains(f64::sqrt(2.) / 2.));
+    /// assert_eq!(radius.upper, radius.lower.next_up());
+    /// ```
+    pub fn radius_with_error<C>(&self) -> Option<Interval<C>>
+    where
+        P: PointLike<C, 2>,
+        C: ToExactRational + EncloseRational,
+    {
+        self.exact_circumcircle().map(|c| C::enclose_sqrt(&c.1))
+    }
+
+    /// Computes a center and radius, both representable in the coordinate type, such that the resulting disk is guaranteed to contain the exact circle. `None` for degenerate circles spanned by 0 or 1 points (or 3 collinear points), and for non-finite coordinates.
+    ///
+    /// The disk thus contains every spanning point, and, since the exact circle computed by the algorithms encloses all input points, every input point as well.
+    /// This is useful whenever floating-point results must not violate containment, e.g., for culling.
+    /// The center is the representable value closest to the exact center, and the radius is inflated by the (provably bounded) distance between both centers, rounded upwards, i.e., by a few units in the last place only.
+    ///
+    /// ```
+    /// use smallest_enclosing_circle::smallest_enclosing_circle;
+    ///
+    /// let points = [[0.1, 0.3], [1.7, -0.2], [0.9, 1.3], [0.8, 0.4]];
+    /// let circle = smallest_enclosing_circle(points);
+    /// let (center, radius) = circle.conservative_bounds().unwrap();
+    /// assert!(radius >= circle.radius().unwrap());
+    /// ```
+    pub fn conservative_bounds<C>(&self) -> Option<([C; 2], C)>
+    where
+        P: PointLike<C, 2>,
+        C: ToExactRational + EncloseRational + Copy,
+    {
+        let (center, squared_radius) = self.exact_circumcircle()?;
+        // Rounds a coordinate of the center to the nearest representable value, together with the exact rounding error.
+        let round = |exact: &BigRational| -> Option<(C, BigRational)> {
+            let Interval { lower, upper } = C::enclose(exact);
+            let [exact_lower, exact_upper] =
+                [lower.to_exact_rational()?, upper.to_exact_rational()?];
+            let (rounded, exact_rounded) = if exact - &exact_lower <= &exact_upper - exact {
+                (lower, exact_lower)
+            } else {
+                (upper, exact_upper)
+            };
+            Some((rounded, exact_rounded - exact))
+        };
+        let [(x, dx), (y, dy)] = [round(&center[0])?, round(&center[1])?];
+        let squared_distance = &dx * &dx + &dy * &dy;
+        let distance = C::enclose_sqrt(&squared_distance)
+            .upper
+            .to_exact_rational()?;
+        let radius = C::enclose_sqrt(&squared_radius).upper.to_exact_rational()?;
+        let radius = C::enclose(&(distance + radius)).upper;
+        Some(([x, y], radius))
+    }
+
+    fn exact_circumcircle<C>(&self) -> Option<([BigRational; 2], BigRational)>
+    where
+        P: PointLike<C, 2>,
+        C: ToExactRational,
+    {
+        let exact = |p: &P| -> Option<[BigRational; 2]> {
+            let [x, y] = p.coordinates();
+            Some([x.to_exact_rational()?, y.to_exact_rational()?])
+        };
+        match self {
+            Circle2D::None => None,
+            Circle2D::One { .. } => None,
+            Circle2D::Two { a, b } => Some(exact_circumcircle2d_two(exact(a)?, exact(b)?)),
+            Circle2D::Three { a, b, c, .. } => {
+                exact_circumcircle2d(exact(a)?, exact(b)?, exact(c)?)
+            }
+        }
+    }
+}
+
+impl<P> Circle2D<P> {
     /// Tests whether the given point lies exactly *on* the circle.
-    pub fn is_on_circle(&self, point: &impl PointLike<f64, 2>) -> bool {
-        self.is_on_circle_with_predicate::<DefaultInCircle>(point)
+    pub fn is_on_circle<C>(&self, point: &impl PointLike<C, 2>) -> bool
+    where
+        P: PointLike<C, 2>,
+        C: PartialEq,
+        DefaultInCircle: InCircle<C>,
+    {
+        self.is_on_circle_with_predicate::<DefaultInCircle, C>(point)
     }
 
     /// Tests whether the given point lies exactly *on* the circle. Uses the custom [`InCircle`] predicate to determine the location.
-    pub fn is_on_circle_with_predicate<IC: InCircle<f64>>(
-        &self,
-        point: &impl PointLike<f64, 2>,
-    ) -> bool {
+    pub fn is_on_circle_with_predicate<IC, C>(&self, point: &impl PointLike<C, 2>) -> bool
+    where
+        P: PointLike<C, 2>,
+        C: PartialEq,
+        IC: InCircle<C>,
+    {
         match self {
             Circle2D::None => false,
             Circle2D::One { p } => p.coordinates() == point.coordinates(),
             Circle2D::Two { a, b } => {
-                let s = self.surrogate().unwrap();
-                let i = IC::in_circle(a, b, &s, point);
+                let i = IC::in_diametral_circle(a, b, point);
                 i == InCircleState::On
             }
             Circle2D::Three { a, b, c, .. } => {
@@ -192,53 +433,59 @@ where
     }
 
     /// Checks for equivalence between two circles in the graphical sense. Two circles are equal iff every spanning point of the other circle is located exactly *on* this circle and vice-versa.
-    pub fn equals(&self, other: &Circle2D<impl PointLike<f64, 2>>) -> bool {
-        self.equals_with_predicate::<DefaultInCircle>(other)
+    pub fn equals<C>(&self, other: &Circle2D<impl PointLike<C, 2>>) -> bool
+    where
+        P: PointLike<C, 2>,
+        C: PartialEq,
+        DefaultInCircle: InCircle<C>,
+    {
+        self.equals_with_predicate::<DefaultInCircle, C>(other)
     }
 
     /// Checks for equivalence between two circles in the graphical sense. Two circles are equal iff every spanning point of the other circle is located exactly *on* this circle and vice-versa. Uses the custom [`InCircle`] predicate to determine locations.
-    pub fn equals_with_predicate<IC: InCircle<f64>>(
-        &self,
-        other: &Circle2D<impl PointLike<f64, 2>>,
-    ) -> bool {
-        self.one_sided_equals_with_predicate::<IC>(other)
-            && other.one_sided_equals_with_predicate::<IC>(self)
+    pub fn equals_with_predicate<IC, C>(&self, other: &Circle2D<impl PointLike<C, 2>>) -> bool
+    where
+        P: PointLike<C, 2>,
+        C: PartialEq,
+        IC: InCircle<C>,
+    {
+        self.one_sided_equals_with_predicate::<IC, C>(other)
+            && other.one_sided_equals_with_predicate::<IC, C>(self)
     }
 
-    fn one_sided_equals_with_predicate<IC: InCircle<f64>>(
-        &self,
-        other: &Circle2D<impl PointLike<f64, 2>>,
-    ) -> bool {
+    fn one_sided_equals_with_predicate<IC, C>(&self, other: &Circle2D<impl PointLike<C, 2>>) -> bool
+    where
+        P: PointLike<C, 2>,
+        C: PartialEq,
+        IC: InCircle<C>,
+    {
         match self {
-            Circle2D::None => match other {
-                Circle2D::None => true,
-                _ => false,
-            },
+            Circle2D::None => matches!(other, Circle2D::None),
             Circle2D::One { p: p1 } => match other {
                 Circle2D::One { p: p2 } => p1.coordinates() == p2.coordinates(),
                 _ => false,
             },
             Circle2D::Two { .. } => match other {
                 Circle2D::Two { a, b } => {
-                    self.is_on_circle_with_predicate::<IC>(a)
-                        && self.is_on_circle_with_predicate::<IC>(b)
+                    self.is_on_circle_with_predicate::<IC, C>(a)
+                        && self.is_on_circle_with_predicate::<IC, C>(b)
                 }
                 Circle2D::Three { a, b, c, .. } => {
-                    self.is_on_circle_with_predicate::<IC>(a)
-                        && self.is_on_circle_with_predicate::<IC>(b)
-                        && self.is_on_circle_with_predicate::<IC>(c)
+                    self.is_on_circle_with_predicate::<IC, C>(a)
+                        && self.is_on_circle_with_predicate::<IC, C>(b)
+                        && self.is_on_circle_with_predicate::<IC, C>(c)
                 }
                 _ => false,
             },
             Circle2D::Three { .. } => match other {
                 Circle2D::Two { a, b } => {
-                    self.is_on_circle_with_predicate::<IC>(a)
-                        && self.is_on_circle_with_predicate::<IC>(b)
+                    self.is_on_circle_with_predicate::<IC, C>(a)
+                        && self.is_on_circle_with_predicate::<IC, C>(b)
                 }
                 Circle2D::Three { a, b, c, .. } => {
-                    self.is_on_circle_with_predicate::<IC>(a)
-                        && self.is_on_circle_with_predicate::<IC>(b)
-                        && self.is_on_circle_with_predicate::<IC>(c)
+                    self.is_on_circle_with_predicate::<IC, C>(a)
+                        && self.is_on_circle_with_predicate::<IC, C>(b)
+                        && self.is_on_circle_with_predicate::<IC, C>(c)
                 }
                 _ => false,
             },
@@ -246,48 +493,232 @@ where
     }
 }
 
-impl<A> Circle2D<A>
-where
-    A: PointLike<f64, 2> + PartialEq,
-{
+impl<A> Circle2D<A> {
     /// Checks whether the given point is contained by the circle, i.e., whether it lies on *or* inside the circle.
-    pub fn contains<P: PointLike<f64, 2> + PartialEq>(&self, point: &P) -> bool {
-        self.contains_with_predicate::<P, DefaultInCircle>(point)
+    pub fn contains<P, C>(&self, point: &P) -> bool
+    where
+        A: PointLike<C, 2>,
+        P: PointLike<C, 2>,
+        C: PartialEq,
+        DefaultInCircle: InCircle<C>,
+    {
+        self.contains_with_predicate::<P, DefaultInCircle, C>(point)
     }
 
     /// Checks whether the given point is contained by the circle, i.e., whether it lies on *or* inside the circle. Uses the custom [`InCircle`] predicate to determine locations.
-    pub fn contains_with_predicate<P: PointLike<f64, 2> + PartialEq, IC: InCircle<f64>>(
-        &self,
-        point: &P,
-    ) -> bool {
+    pub fn contains_with_predicate<P, IC, C>(&self, point: &P) -> bool
+    where
+        A: PointLike<C, 2>,
+        P: PointLike<C, 2>,
+        C: PartialEq,
+        IC: InCircle<C>,
+    {
+        self.locate_with_predicate::<P, IC, C>(point)
+            .is_some_and(|i| i != InCircleState::Outside)
+    }
+
+    /// Determines whether the given point lies inside, outside, or on the circle, independent of the orientation of the spanning points. `None` for [`Circle2D::None`], a [`Circle2D::One`] is treated as a circle of radius zero.
+    pub(crate) fn locate_with_predicate<P, IC, C>(&self, point: &P) -> Option<InCircleState>
+    where
+        A: PointLike<C, 2>,
+        P: PointLike<C, 2>,
+        C: PartialEq,
+        IC: InCircle<C>,
+    {
         match self {
-            Circle2D::None => false,
-            Circle2D::One { p } => p.coordinates() == point.coordinates(),
-            Circle2D::Two { a, b } => {
-                let s = self.surrogate().unwrap();
-                let i = IC::in_circle(a, b, &s, point);
-                i != InCircleState::Outside
+            Circle2D::None => None,
+            Circle2D::One { p } if p.coordinates() == point.coordinates() => {
+                Some(InCircleState::On)
             }
+            Circle2D::One { .. } => Some(InCircleState::Outside),
+            Circle2D::Two { a, b } => Some(IC::in_diametral_circle(a, b, point)),
             Circle2D::Three {
                 a,
                 b,
                 c,
                 counter_clockwise,
-            } => {
-                let i = IC::in_circle(a, b, c, point);
-                (*counter_clockwise && i != InCircleState::Outside)
-                    || (!counter_clockwise && i != InCircleState::Inside)
+            } => Some(match (IC::in_circle(a, b, c, point), counter_clockwise) {
+                (InCircleState::Inside, false) => InCircleState::Outside,
+                (InCircleState::Outside, false) => InCircleState::Inside,
+                (i, _) => i,
+            }),
+        }
+    }
+}
+
+impl<A> Circle2D<A> {
+    /// Checks whether the given point is contained by the circle up to a tolerance, i.e., whether its distance to the center exceeds the radius by at most `max(abs_tol, rel_tol * radius)`.
+    ///
+    /// Points that are contained exactly (see [`Circle2D::contains`]) are always accepted, regardless of rounding errors in the computed center and radius. A [`Circle2D::One`] is treated as a circle of radius zero.
+    ///
+    /// ```
+    /// use smallest_enclosing_circle::Circle2D;
+    ///
+    /// let circle = Circle2D::new(&[[0., 0.], [2., 0.]]);
+    /// assert!(!circle.contains(&[1., 1.001]));
+    /// assert!(circle.contains_within(&[1., 1.001], 0.01, 0.));
+    /// assert!(circle.contains_within(&[1., 1.001], 0., 0.01));
+    /// ```
+    pub fn contains_within<P, C>(&self, point: &P, abs_tol: C, rel_tol: C) -> bool
+    where
+        A: PointLike<C, 2>,
+        P: PointLike<C, 2>,
+        C: Real + ConstTwo + Finite,
+        DefaultInCircle: InCircle<C>,
+        DefaultOrientationArea: OrientationArea<C>,
+    {
+        if self.contains(point) {
+            return true;
+        }
+        match self.center_and_radius() {
+            Some((center, radius)) => {
+                distance(center, point.coordinates()) <= radius + abs_tol.max(rel_tol * radius)
             }
+            None => false,
+        }
+    }
+
+    /// Computes the (unsigned) distance between the given point and the boundary of the circle. `None` for [`Circle2D::None`], a [`Circle2D::One`] is treated as a circle of radius zero.
+    ///
+    /// This procedure is not numerically robust, use [`Circle2D::contains`] for exact containment checks.
+    ///
+    /// ```
+    /// use smallest_enclosing_circle::Circle2D;
+    ///
+    /// let circle = Circle2D::new(&[[0., 0.], [2., 0.]]);
+    /// assert_eq!(circle.distance_to_boundary(&[1., 0.]), Some(1.));
+    /// assert_eq!(circle.distance_to_boundary(&[4., 0.]), Some(2.));
+    /// ```
+    pub fn distance_to_boundary<P, C>(&self, point: &P) -> Option<C>
+    where
+        A: PointLike<C, 2>,
+        P: PointLike<C, 2>,
+        C: Real + ConstTwo + Finite,
+        DefaultOrientationArea: OrientationArea<C>,
+    {
+        self.center_and_radius()
+            .map(|(center, radius)| (distance(center, point.coordinates()) - radius).abs())
+    }
+
+    /// Computes the signed distance between the given point and the boundary of the circle, negative inside and positive outside. `None` for [`Circle2D::None`], a [`Circle2D::One`] is treated as a circle of radius zero.
+    ///
+    /// The magnitude is subject to rounding errors, but the sign is determined exactly by the [`DefaultInCircle`] predicate, i.e., it agrees with [`Circle2D::contains`]. Only points on the circle yield zero, if the magnitude of any other point rounds to zero, the smallest positive value (with the exact sign) is returned instead.
+    ///
+    /// ```
+    /// use smallest_enclosing_circle::Circle2D;
+    ///
+    /// let circle = Circle2D::new(&[[0., 0.], [2., 0.]]);
+    /// assert_eq!(circle.signed_distance(&[1., 0.]), Some(-1.));
+    /// assert_eq!(circle.signed_distance(&[4., 0.]), Some(2.));
+    /// ```
+    pub fn signed_distance<P, C>(&self, point: &P) -> Option<C>
+    where
+        A: PointLike<C, 2>,
+        P: PointLike<C, 2>,
+        C: Real + ConstTwo + Finite,
+        DefaultInCircle: InCircle<C>,
+        DefaultOrientationArea: OrientationArea<C>,
+    {
+        let (center, radius) = self.center_and_radius()?;
+        let value = distance(center, point.coordinates()) - radius;
+        self.with_exact_sign(point, value)
+    }
+
+    /// Computes the power of the given point with respect to the circle, i.e., its squared distance to the center minus the squared radius. `None` for [`Circle2D::None`], a [`Circle2D::One`] is treated as a circle of radius zero.
+    ///
+    /// The magnitude is subject to rounding errors, but the sign is determined exactly by the [`DefaultInCircle`] predicate, i.e., it agrees with [`Circle2D::contains`]. Only points on the circle yield zero, if the magnitude of any other point rounds to zero, the smallest positive value (with the exact sign) is returned instead.
+    ///
+    /// ```
+    /// use smallest_enclosing_circle::Circle2D;
+    ///
+    /// let circle = Circle2D::new(&[[0., 0.], [2., 0.]]);
+    /// assert_eq!(circle.power(&[1., 0.]), Some(-1.));
+    /// assert_eq!(circle.power(&[4., 0.]), Some(8.));
+    /// ```
+    pub fn power<P, C>(&self, point: &P) -> Option<C>
+    where
+        A: PointLike<C, 2>,
+        P: PointLike<C, 2>,
+        C: Real + ConstTwo + Finite,
+        DefaultInCircle: InCircle<C>,
+        DefaultOrientationArea: OrientationArea<C>,
+    {
+        let (center, radius) = self.center_and_radius()?;
+        let [dx, dy] = [0, 1].map(|i| point.coordinates()[i] - center[i]);
+        let value = dx * dx + dy * dy - radius * radius;
+        self.with_exact_sign(point, value)
+    }
+
+    /// Finds the point farthest from the center of the circle, returns its index and its distance to the center. `None` for [`Circle2D::None`] or if no points are given, a [`Circle2D::One`] is treated as a circle of radius zero. Ties resolve to the first point.
+    ///
+    /// ```
+    /// use smallest_enclosing_circle::Circle2D;
+    ///
+    /// let circle = Circle2D::new(&[[0., 0.], [2., 0.]]);
+    /// assert_eq!(circle.farthest_point(&[[1., 0.], [1., 3.], [0., 1.]]), Some((1, 3.)));
+    /// ```
+    pub fn farthest_point<P, C>(&self, points: &[P]) -> Option<(usize, C)>
+    where
+        A: PointLike<C, 2>,
+        P: PointLike<C, 2>,
+        C: Real + ConstTwo + Finite,
+        DefaultOrientationArea: OrientationArea<C>,
+    {
+        let (center, _) = self.center_and_radius()?;
+        points
+            .iter()
+            .map(|p| distance(center, p.coordinates()))
+            .enumerate()
+            .fold(None, |farthest, (i, d)| match farthest {
+                Some((_, max)) if d > max => Some((i, d)),
+                None => Some((i, d)),
+                _ => farthest,
+            })
+    }
+
+    /// Replaces the sign of the given value by the exact location of the point. Values of points that are not on the circle but round to zero are replaced by the smallest positive value, so that the sign is retained.
+    fn with_exact_sign<P, C>(&self, point: &P, value: C) -> Option<C>
+    where
+        A: PointLike<C, 2>,
+        P: PointLike<C, 2>,
+        C: Real,
+        DefaultInCircle: InCircle<C>,
+    {
+        let magnitude = value.abs().max(C::min_positive_value());
+        self.locate_with_predicate::<P, DefaultInCircle, C>(point)
+            .map(|i| match i {
+                InCircleState::Inside => -magnitude,
+                InCircleState::Outside => magnitude,
+                InCircleState::On => C::zero(),
+            })
+    }
+
+    /// Like [`CircumCircle::circumcircle`], but treats a [`Circle2D::One`] as a circle of radius zero.
+    fn center_and_radius<C>(&self) -> Option<([C; 2], C)>
+    where
+        A: PointLike<C, 2>,
+        C: Real + ConstTwo + Finite,
+        DefaultOrientationArea: OrientationArea<C>,
+    {
+        match self {
+            Circle2D::One { p } => Some((p.coordinates(), C::zero())),
+            _ => self.circumcircle(),
         }
     }
 }
 
+/// Computes the euclidean distance between two points.
+fn distance<C: Real>(a: [C; 2], b: [C; 2]) -> C {
+    (a[0] - b[0]).hypot(a[1] - b[1])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     mod circle {
         use super::*;
+        #[allow(deprecated)]
         mod surrogate {
             use super::*;
 
@@ -308,6 +739,79 @@ mod tests {
             }
         }
 
+        mod try_new {
+            use super::*;
+
+            #[test]
+            fn no_point() {
+                assert_eq!(Circle2D::<[f64; 2]>::try_new(&[]), Ok(Circle2D::None))
+            }
+
+            #[test]
+            fn duplicates() {
+                assert_eq!(
+                    Circle2D::try_new(&[[0., 0.], [1., 0.], [0., 0.]]),
+                    Ok(Circle2D::Two {
+                        a: [1., 0.],
+                        b: [0., 0.]
+                    })
+                )
+            }
+
+            #[test]
+            fn duplicates_2() {
+                assert_eq!(
+                    Circle2D::try_new(&[[0., 0.], [0., 0.], [1., 0.]]),
+                    Ok(Circle2D::Two {
+                        a: [0., 0.],
+                        b: [1., 0.]
+                    })
+                )
+            }
+
+            #[test]
+            fn three_points() {
+                assert_eq!(
+                    Circle2D::try_new(&[[0., 0.], [1., 0.], [1., 1.]]),
+                    Ok(Circle2D::new(&[[0., 0.], [1., 0.], [1., 1.]]))
+                )
+            }
+
+            #[test]
+            fn too_many_points() {
+                assert_eq!(
+                    Circle2D::try_new(&[[0., 0.], [1., 0.], [1., 1.], [0., 1.]]),
+                    Err(CircleError::TooManyPoints(4))
+                )
+            }
+
+            #[test]
+            fn collinear() {
+                assert_eq!(
+                    Circle2D::try_new(&[[0., 0.], [1., 1.], [2., 2.]]),
+                    Err(CircleError::Collinear)
+                )
+            }
+
+            #[test]
+            fn non_finite() {
+                assert_eq!(
+                    Circle2D::try_new(&[[0., 0.], [f64::NAN, 1.]]),
+                    Err(CircleError::NonFinite)
+                );
+                assert_eq!(
+                    Circle2D::try_new(&[[f64::NEG_INFINITY, 0.]]),
+                    Err(CircleError::NonFinite)
+                )
+            }
+
+            #[test]
+            #[should_panic(expected = "at most 3 points, but 4 points were supplied")]
+            fn panicking_version() {
+                Circle2D::new(&[[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
+            }
+        }
+
         mod radius {
             use super::*;
 
@@ -368,6 +872,173 @@ mod tests {
             }
         }
 
+        mod f32 {
+            use super::*;
+
+            #[test]
+            fn center_and_radius() {
+                let circle = Circle2D::<[f32; 2]>::new(&[[0., 0.], [1.0, 0.], [1.0, 1.0]]);
+                assert_eq!(circle.center(), Some([0.5, 0.5]));
+                assert_eq!(circle.radius(), Some(f32::sqrt(2.) / 2.));
+            }
+
+            #[test]
+            fn contains() {
+                let circle = Circle2D::<[f32; 2]>::new(&[[0., 0.], [1.0, 0.], [1.0, 1.0]]);
+                assert!(circle.contains(&[0.5f32, 0.5]));
+                assert!(circle.contains(&[0.0f32, 1.0]));
+                assert!(!circle.contains(&[1.5f32, 1.5]));
+                assert!(circle.is_on_circle(&[0.0f32, 1.0]));
+            }
+        }
+
+        mod with_error {
+            use super::*;
+
+            #[test]
+            fn degenerate() {
+                assert_eq!(Circle2D::<[f64; 2]>::new(&[]).center_with_error(), None);
+                assert_eq!(Circle2D::new(&[[1., 1.]]).radius_with_error(), None);
+                assert_eq!(
+                    Circle2D::new(&[[f64::NAN, 1.], [0., 0.]]).radius_with_error(),
+                    None
+                );
+            }
+
+            #[test]
+            fn exact() {
+                let circle = Circle2D::new(&[[0., 0.], [2.0, 0.], [2.0, 2.0]]);
+                let one = Interval {
+                    lower: 1.0,
+                    upper: 1.0,
+                };
+                assert_eq!(circle.center_with_error(), Some([one, one]));
+            }
+
+            #[test]
+            fn encloses_exact_values() {
+                let circle = Circle2D::<[f64; 2]>::new(&[[0.1, 0.3], [1.7, -0.2], [0.9, 1.3]]);
+                let exact_center = circle.exact_center().unwrap();
+                let exact_squared_radius = circle.exact_squared_radius().unwrap();
+                let center = circle.center_with_error().unwrap();
+                let radius = circle.radius_with_error().unwrap();
+                for i in 0..2 {
+                    assert!(BigRational::from_float(center[i].lower).unwrap() <= exact_center[i]);
+                    assert!(BigRational::from_float(center[i].upper).unwrap() >= exact_center[i]);
+                    assert!(center[i].upper <= center[i].lower.next_up());
+                }
+                let [lower, upper] = [radius.lower, radius.upper].map(|x| {
+                    let x = BigRational::from_float(x).unwrap();
+                    &x * &x
+                });
+                assert!(lower <= exact_squared_radius && exact_squared_radius <= upper);
+                assert!(radius.upper <= radius.lower.next_up());
+            }
+
+            #[test]
+            fn f32() {
+                let circle = Circle2D::new(&[[0.1f32, 0.3], [1.7, -0.2], [0.9, 1.3]]);
+                let radius = circle.radius_with_error().unwrap();
+                assert!(radius.upper <= radius.lower.next_up());
+                assert!(radius.contains(circle.radius().unwrap()));
+            }
+        }
+
+        mod conservative_bounds {
+            use super::*;
+
+            use crate::algorithm::smallest_enclosing_circle;
+
+            fn exact_squared_distance(a: [f64; 2], b: [f64; 2]) -> BigRational {
+                let [dx, dy] = [0, 1].map(|i| {
+                    BigRational::from_float(a[i]).unwrap() - BigRational::from_float(b[i]).unwrap()
+                });
+                &dx * &dx + &dy * &dy
+            }
+
+            #[test]
+            fn degenerate() {
+                assert_eq!(Circle2D::<[f64; 2]>::new(&[]).conservative_bounds(), None);
+                assert_eq!(Circle2D::new(&[[1., 1.]]).conservative_bounds(), None);
+            }
+
+            #[test]
+            fn exact() {
+                assert_eq!(
+                    Circle2D::new(&[[0., 0.], [2., 0.], [2., 2.]]).conservative_bounds(),
+                    Some(([1., 1.], f64::sqrt(2.)))
+                );
+            }
+
+            #[test]
+            fn contains_all_points() {
+                let mut rng = crate::random::SplitMix64::new(12345);
+                let mut random = || rng.next_f64() * 1e3 - 0.5e3;
+                for _ in 0..20 {
+                    let points: Vec<[f64; 2]> = (0..100).map(|_| [random(), random()]).collect();
+                    let circle = smallest_enclosing_circle(points.clone());
+                    let (center, radius) = circle.conservative_bounds().unwrap();
+                    let exact_radius = BigRational::from_float(radius).unwrap();
+                    let squared_radius = &exact_radius * &exact_radius;
+                    for p in points {
+                        assert!(exact_squared_distance(center, p) <= squared_radius);
+                    }
+                    assert!(radius - circle.radius().unwrap() < 1e-9);
+                }
+            }
+
+            #[test]
+            fn f32() {
+                let circle = Circle2D::new(&[[0.1f32, 0.3], [1.7, -0.2], [0.9, 1.3]]);
+                let (_, radius) = circle.conservative_bounds().unwrap();
+                assert!(radius >= circle.radius_with_error().unwrap().upper);
+            }
+        }
+
+        mod integer {
+            use super::*;
+
+            #[test]
+            fn contains() {
+                let circle = Circle2D::new(&[[0i64, 0], [2, 0], [2, 2]]);
+                assert!(circle.contains(&[1i64, 1]));
+                assert!(circle.contains(&[0i64, 2]));
+                assert!(!circle.contains(&[3i64, 3]));
+                assert!(circle.is_on_circle(&[0i64, 2]));
+            }
+
+            #[test]
+            fn contains_two_points() {
+                let m = i64::MAX;
+                let circle = Circle2D::new(&[[-m, 0], [m, 0]]);
+                assert!(circle.is_on_circle(&[0, m]));
+                assert!(circle.contains(&[0, m - 1]));
+                assert!(!circle.contains(&[1, m]));
+            }
+
+            #[test]
+            fn exact_center_and_radius() {
+                let circle = Circle2D::new(&[[0i32, 0], [3, 0], [0, 1]]);
+                let r = |n: i64, d: i64| BigRational::new(n.into(), d.into());
+                assert_eq!(circle.exact_center(), Some([r(3, 2), r(1, 2)]));
+                assert_eq!(circle.exact_squared_radius(), Some(r(5, 2)));
+            }
+
+            #[test]
+            fn exact_center_and_radius_two_points() {
+                let circle = Circle2D::new(&[[1i64, 1], [2, 4]]);
+                let r = |n: i64, d: i64| BigRational::new(n.into(), d.into());
+                assert_eq!(circle.exact_center(), Some([r(3, 2), r(5, 2)]));
+                assert_eq!(circle.exact_squared_radius(), Some(r(10, 4)));
+            }
+
+            #[test]
+            fn exact_center_degenerate() {
+                assert_eq!(Circle2D::new(&[[1i64, 1]]).exact_center(), None);
+                assert_eq!(Circle2D::<[i64; 2]>::new(&[]).exact_squared_radius(), None);
+            }
+        }
+
         mod is_on_circle {
             use super::*;
 
@@ -555,6 +1226,209 @@ mod tests {
             }
         }
 
+        mod contains_within {
+            use super::*;
+
+            #[test]
+            fn empty() {
+                assert!(!Circle2D::<[f64; 2]>::new(&[]).contains_within(&[0., 0.], 1., 1.));
+            }
+
+            #[test]
+            fn one_point() {
+                let circle = Circle2D::new(&[[1., 1.]]);
+                assert!(circle.contains_within(&[1., 1.5], 0.5, 0.));
+                assert!(!circle.contains_within(&[1., 1.5], 0.4, 0.));
+                assert!(!circle.contains_within(&[1., 1.5], 0., 100.));
+            }
+
+            #[test]
+            fn absolute_tolerance() {
+                let circle = Circle2D::new(&[[0., 0.], [4., 0.], [2., 2.]]);
+                assert!(circle.contains_within(&[2., 2.5], 0.5, 0.));
+                assert!(!circle.contains_within(&[2., 2.5], 0.4, 0.));
+            }
+
+            #[test]
+            fn relative_tolerance() {
+                let circle = Circle2D::new(&[[0., 0.], [4., 0.], [2., 2.]]);
+                assert!(circle.contains_within(&[2., 2.5], 0., 0.25));
+                assert!(!circle.contains_within(&[2., 2.5], 0., 0.2));
+                assert!(circle.contains_within(&[2., 2.5], 0.1, 0.25));
+            }
+
+            #[test]
+            fn exactly_contained() {
+                // The computed radius is inexact, but points that are contained exactly are never rejected.
+                let points = [[0.1, 0.3], [1.7, -0.2], [0.9, 1.3]];
+                let circle = Circle2D::new(&points);
+                for p in points {
+                    assert!(circle.contains_within(&p, 0., 0.));
+                }
+            }
+        }
+
+        mod signed_distance {
+            use super::*;
+
+            #[test]
+            fn empty() {
+                assert_eq!(
+                    Circle2D::<[f64; 2]>::new(&[]).signed_distance(&[0., 0.]),
+                    None
+                );
+            }
+
+            #[test]
+            fn one_point() {
+                let circle = Circle2D::new(&[[1., 1.]]);
+                assert_eq!(circle.signed_distance(&[4., 5.]), Some(5.));
+                assert_eq!(circle.signed_distance(&[1., 1.]), Some(0.));
+            }
+
+            #[test]
+            fn inside_and_outside() {
+                let circle = Circle2D::new(&[[0., 0.], [4., 0.], [2., 2.]]);
+                assert_eq!(circle.signed_distance(&[2., 0.]), Some(-2.));
+                assert_eq!(circle.signed_distance(&[2., 5.]), Some(3.));
+            }
+
+            #[test]
+            fn clockwise() {
+                let circle = Circle2D::new(&[[0., 0.], [2., 2.], [4., 0.]]);
+                assert_eq!(circle.signed_distance(&[2., 0.]), Some(-2.));
+                assert_eq!(circle.signed_distance(&[2., 5.]), Some(3.));
+            }
+
+            #[test]
+            fn exact_sign() {
+                let points = [[0.1, 0.3], [1.7, -0.2], [0.9, 1.3]];
+                let circle = Circle2D::new(&points);
+                for p in points {
+                    assert_eq!(circle.signed_distance(&p), Some(0.));
+                }
+                let circle = Circle2D::new(&[[0.1, 0.], [-0.1, 0.]]);
+                assert_eq!(circle.signed_distance(&[0., 0.1]), Some(0.));
+                assert!(circle.signed_distance(&[0., 0.1f64.next_up()]).unwrap() > 0.);
+                assert!(circle.signed_distance(&[0., 0.1f64.next_down()]).unwrap() < 0.);
+            }
+
+            #[test]
+            fn rounded_to_zero() {
+                // The distances round to the radius, the exact location still determines the sign.
+                let circle = Circle2D::new(&[[0., 0.], [4., 0.]]);
+                assert_eq!(circle.signed_distance(&[0., 1e-9]), Some(f64::MIN_POSITIVE));
+                assert_eq!(
+                    circle.signed_distance(&[1e-20, 0.]),
+                    Some(-f64::MIN_POSITIVE)
+                );
+                assert_eq!(circle.signed_distance(&[4., 0.]), Some(0.));
+            }
+        }
+
+        mod power {
+            use super::*;
+
+            #[test]
+            fn empty() {
+                assert_eq!(Circle2D::<[f64; 2]>::new(&[]).power(&[0., 0.]), None);
+            }
+
+            #[test]
+            fn one_point() {
+                assert_eq!(Circle2D::new(&[[1., 1.]]).power(&[4., 5.]), Some(25.));
+            }
+
+            #[test]
+            fn inside_and_outside() {
+                let circle = Circle2D::new(&[[0., 0.], [4., 0.], [2., 2.]]);
+                assert_eq!(circle.power(&[2., 0.]), Some(-4.));
+                assert_eq!(circle.power(&[2., 5.]), Some(21.));
+                assert_eq!(circle.power(&[0., 0.]), Some(0.));
+            }
+
+            #[test]
+            fn f32() {
+                let circle = Circle2D::new(&[[0f32, 0.], [4., 0.]]);
+                assert_eq!(circle.power(&[2., 3.]), Some(5.));
+            }
+
+            #[test]
+            fn rounded_to_zero() {
+                let circle = Circle2D::new(&[[0., 0.], [4., 0.]]);
+                assert_eq!(circle.power(&[0., 1e-9]), Some(f64::MIN_POSITIVE));
+                assert_eq!(circle.power(&[1e-20, 0.]), Some(-f64::MIN_POSITIVE));
+            }
+        }
+
+        mod farthest_point {
+            use super::*;
+
+            #[test]
+            fn empty() {
+                assert_eq!(
+                    Circle2D::<[f64; 2]>::new(&[]).farthest_point(&[[0., 0.]]),
+                    None
+                );
+                assert_eq!(
+                    Circle2D::new(&[[0., 0.], [2., 0.]]).farthest_point::<[f64; 2], _>(&[]),
+                    None
+                );
+            }
+
+            #[test]
+            fn farthest() {
+                let circle = Circle2D::new(&[[0., 0.], [4., 0.], [2., 2.]]);
+                assert_eq!(
+                    circle.farthest_point(&[[2., 1.], [5., 0.], [2., -1.]]),
+                    Some((1, 3.))
+                );
+            }
+
+            #[test]
+            fn ties() {
+                let circle = Circle2D::new(&[[0., 0.], [4., 0.], [2., 2.]]);
+                assert_eq!(
+                    circle.farthest_point(&[[2., 1.], [0., 0.], [4., 0.]]),
+                    Some((1, 2.))
+                );
+            }
+        }
+
+        mod distance_to_boundary {
+            use super::*;
+
+            #[test]
+            fn empty() {
+                assert_eq!(
+                    Circle2D::<[f64; 2]>::new(&[]).distance_to_boundary(&[0., 0.]),
+                    None
+                );
+            }
+
+            #[test]
+            fn one_point() {
+                assert_eq!(
+                    Circle2D::new(&[[1., 1.]]).distance_to_boundary(&[4., 5.]),
+                    Some(5.)
+                );
+            }
+
+            #[test]
+            fn inside_and_outside() {
+                let circle = Circle2D::new(&[[0., 0.], [4., 0.], [2., 2.]]);
+                assert_eq!(circle.distance_to_boundary(&[2., 0.]), Some(2.));
+                assert_eq!(circle.distance_to_boundary(&[2., 5.]), Some(3.));
+                assert_eq!(circle.distance_to_boundary(&[4., 0.]), Some(0.));
+            }
+
+            #[test]
+            fn f32() {
+                let circle = Circle2D::new(&[[0f32, 0.], [4., 0.]]);
+                assert_eq!(circle.distance_to_boundary(&[2., 3.]), Some(1.));
+            }
+        }
+
         mod contains {
             use super::*;
 
@@ -598,6 +1472,15 @@ mod tests {
                 assert!(!Circle2D::<[f64; 2]>::new(&[[0., 0.], [1.0, 0.]]).contains(&[2.0, 0.]));
             }
 
+            #[test]
+            fn contains_two_points_exact() {
+                let circle = Circle2D::<[f64; 2]>::new(&[[0.1, 0.], [-0.1, 0.]]);
+                assert!(circle.contains(&[0., 0.1]));
+                assert!(circle.is_on_circle(&[0., 0.1]));
+                assert!(!circle.contains(&[0., 0.1f64.next_up()]));
+                assert!(!circle.is_on_circle(&[0., 0.1f64.next_down()]));
+            }
+
             #[test]
             fn contains_9() {
                 assert!(
@@ -0,0 +1,283 @@
+use num::traits::{float::TotalOrder, real::Real};
+
+use crate::{
+    geometry::{
+        apollonius::{enclosing_circle_of_three_disks2d, enclosing_circle_of_two_disks2d},
+        num::ConstTwo,
+    },
+    predicates::{
+        disk_in_circle::{DefaultDiskInCircle, DiskInCircle},
+        in_circle::InCircleState,
+    },
+};
+
+/// Defines methods that extract the center and radius of a disk-like object. Implemented for `([C; 2], C)` tuples and [`Disk`]. Implement this trait for your own disk type (e.g., a circular collider) if you want to use it directly.
+pub trait DiskLike<C> {
+    fn center(&self) -> [C; 2];
+    fn radius(&self) -> C;
+}
+
+impl<C: Copy> DiskLike<C> for ([C; 2], C) {
+    fn center(&self) -> [C; 2] {
+        self.0
+    }
+
+    fn radius(&self) -> C {
+        self.1
+    }
+}
+
+impl<C: Copy, D: DiskLike<C>> DiskLike<C> for &D {
+    fn center(&self) -> [C; 2] {
+        (**self).center()
+    }
+
+    fn radius(&self) -> C {
+        (**self).radius()
+    }
+}
+
+/// A disk given by its center and (non-negative) radius.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Disk<C> {
+    pub center: [C; 2],
+    pub radius: C,
+}
+
+impl<C> Disk<C> {
+    pub fn new(center: [C; 2], radius: C) -> Self {
+        Disk { center, radius }
+    }
+}
+
+impl<C: Copy> DiskLike<C> for Disk<C> {
+    fn center(&self) -> [C; 2] {
+        self.center
+    }
+
+    fn radius(&self) -> C {
+        self.radius
+    }
+}
+
+/// Represents the result of [`crate::algorithm::smallest_enclosing_circle_of_circles`], a circle defined by up to three disks that touch it from the inside (disks that *span* the circle).
+///
+/// This enum has four variants, analogous to [`crate::Circle2D`]:
+/// - [`DiskCircle2D::None`]: No disks, and thus, no circle.
+/// - [`DiskCircle2D::One`]: The circle is the single given disk itself.
+/// - [`DiskCircle2D::Two`]: The circle touches both disks, its center lies on the line through their centers.
+/// - [`DiskCircle2D::Three`]: The circle touches all three disks (a solution of Apollonius' problem).
+///
+/// Use [`DiskCircle2D::disk`] (or [`DiskCircle2D::center`] and [`DiskCircle2D::radius`]) to obtain the actual circle.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum DiskCircle2D<D> {
+    None,
+    One { a: D },
+    Two { a: D, b: D },
+    Three { a: D, b: D, c: D },
+}
+
+impl<D> DiskCircle2D<D> {
+    /// Creates a new [`DiskCircle2D`] from up to three disks.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if more than three disks are given.
+    pub fn new(disks: &[D]) -> Self
+    where
+        D: Copy,
+    {
+        match *disks {
+            [] => DiskCircle2D::None,
+            [a] => DiskCircle2D::One { a },
+            [a, b] => DiskCircle2D::Two { a, b },
+            [a, b, c] => DiskCircle2D::Three { a, b, c },
+            _ => panic!(
+                "a circle is spanned by at most 3 disks, but {} disks were supplied",
+                disks.len()
+            ),
+        }
+    }
+
+    /// Computes the circle, i.e., the smallest disk that encloses the spanning disks and touches each of them. `None` for [`DiskCircle2D::None`].
+    ///
+    /// This procedure is not numerically robust. If no circle touching all three disks exists numerically, the smallest of the circles spanned by two of the disks that encloses the remaining one (up to a tolerance) is returned instead.
+    ///
+    /// ```
+    /// use smallest_enclosing_circle::disk::{Disk, DiskCircle2D};
+    ///
+    /// let circle = DiskCircle2D::new(&[([0., 0.], 1.), ([4., 0.], 1.)]);
+    /// assert_eq!(circle.disk(), Some(Disk::new([2., 0.], 3.)));
+    /// ```
+    pub fn disk<C>(&self) -> Option<Disk<C>>
+    where
+        D: DiskLike<C>,
+        C: Real + ConstTwo + TotalOrder,
+        DefaultDiskInCircle: DiskInCircle<C>,
+    {
+        self.disk_with_predicate::<DefaultDiskInCircle, C>()
+    }
+
+    /// Computes the circle, see [`DiskCircle2D::disk`]. Uses the custom [`DiskInCircle`] predicate to choose a fallback circle for three disks.
+    pub fn disk_with_predicate<DIC, C>(&self) -> Option<Disk<C>>
+    where
+        D: DiskLike<C>,
+        C: Real + ConstTwo + TotalOrder,
+        DIC: DiskInCircle<C>,
+    {
+        let disk = |d: &D| (d.center(), d.radius());
+        let (center, radius) = match self {
+            DiskCircle2D::None => return None,
+            DiskCircle2D::One { a } => disk(a),
+            DiskCircle2D::Two { a, b } => enclosing_circle_of_two_disks2d(disk(a), disk(b)),
+            DiskCircle2D::Three { a, b, c } => {
+                let [a, b, c] = [disk(a), disk(b), disk(c)];
+                match enclosing_circle_of_three_disks2d(a, b, c) {
+                    Some(circle) => circle,
+                    None => [(a, b, c), (b, c, a), (c, a, b)]
+                        .into_iter()
+                        .map(|(a, b, c)| (enclosing_circle_of_two_disks2d(a, b), c))
+                        .filter(|((center, radius), c)| {
+                            DIC::disk_in_circle(center, *radius, c) != InCircleState::Outside
+                        })
+                        .map(|(circle, _)| circle)
+                        .min_by(|a, b| a.1.total_cmp(&b.1))
+                        .unwrap_or_else(|| {
+                            let [ab, bc, ca] = [(a, b), (b, c), (c, a)]
+                                .map(|(a, b)| enclosing_circle_of_two_disks2d(a, b));
+                            [bc, ca].into_iter().fold(ab, |max, circle| {
+                                if circle.1 > max.1 {
+                                    circle
+                                } else {
+                                    max
+                                }
+                            })
+                        }),
+                }
+            }
+        };
+        Some(Disk::new(center, radius))
+    }
+
+    /// Computes the center of the circle. `None` for [`DiskCircle2D::None`]. See [`DiskCircle2D::disk`].
+    pub fn center<C>(&self) -> Option<[C; 2]>
+    where
+        D: DiskLike<C>,
+        C: Real + ConstTwo + TotalOrder,
+        DefaultDiskInCircle: DiskInCircle<C>,
+    {
+        self.disk().map(|d| d.center)
+    }
+
+    /// Computes the radius of the circle. `None` for [`DiskCircle2D::None`]. See [`DiskCircle2D::disk`].
+    pub fn radius<C>(&self) -> Option<C>
+    where
+        D: DiskLike<C>,
+        C: Real + ConstTwo + TotalOrder,
+        DefaultDiskInCircle: DiskInCircle<C>,
+    {
+        self.disk().map(|d| d.radius)
+    }
+
+    /// Checks whether the given disk is contained by the circle (up to the tolerance of the [`DefaultDiskInCircle`] predicate).
+    pub fn contains<Q, C>(&self, disk: &Q) -> bool
+    where
+        D: DiskLike<C>,
+        Q: DiskLike<C>,
+        C: Real + ConstTwo + TotalOrder,
+        DefaultDiskInCircle: DiskInCircle<C>,
+    {
+        self.contains_with_predicate::<Q, DefaultDiskInCircle, C>(disk)
+    }
+
+    /// Checks whether the given disk is contained by the circle. Uses the custom [`DiskInCircle`] predicate to determine locations.
+    pub fn contains_with_predicate<Q, DIC, C>(&self, disk: &Q) -> bool
+    where
+        D: DiskLike<C>,
+        Q: DiskLike<C>,
+        C: Real + ConstTwo + TotalOrder,
+        DIC: DiskInCircle<C>,
+    {
+        match self.disk_with_predicate::<DIC, C>() {
+            Some(circle) => {
+                DIC::disk_in_circle(&circle.center, circle.radius, disk) != InCircleState::Outside
+            }
+            None => false,
+        }
+    }
+}
+
+impl<D> DiskCircle2D<D> {
+    /// Maps the spanning disks with the given function, keeping the variant as is. The mapping must not change the geometry of the disks.
+    pub(crate) fn map<E>(self, mut f: impl FnMut(D) -> E) -> DiskCircle2D<E> {
+        match self {
+            DiskCircle2D::None => DiskCircle2D::None,
+            DiskCircle2D::One { a } => DiskCircle2D::One { a: f(a) },
+            DiskCircle2D::Two { a, b } => DiskCircle2D::Two { a: f(a), b: f(b) },
+            DiskCircle2D::Three { a, b, c } => DiskCircle2D::Three {
+                a: f(a),
+                b: f(b),
+                c: f(c),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn none() {
+        assert_eq!(DiskCircle2D::<Disk<f64>>::new(&[]).disk(), None);
+    }
+
+    #[test]
+    fn one() {
+        assert_eq!(
+            DiskCircle2D::new(&[([1., 2.], 3.)]).disk(),
+            Some(Disk::new([1., 2.], 3.))
+        );
+    }
+
+    #[test]
+    fn two_nested() {
+        assert_eq!(
+            DiskCircle2D::new(&[([0., 0.], 1.), ([0.5, 0.], 3.)]).disk(),
+            Some(Disk::new([0.5, 0.], 3.))
+        );
+    }
+
+    #[test]
+    fn three() {
+        let disk = DiskCircle2D::new(&[([-2., 0.], 1.), ([2., 0.], 1.), ([0., 2.], 1.)])
+            .disk()
+            .unwrap();
+        assert!(disk.center[0].abs() < 1e-12);
+        assert!((disk.center[1].abs()) < 1e-12);
+        assert!((disk.radius - 3.).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "at most 3 disks")]
+    fn too_many() {
+        DiskCircle2D::new(&[([0., 0.], 1.); 4]);
+    }
+
+    #[test]
+    fn contains() {
+        let circle = DiskCircle2D::new(&[([0., 0.], 1.), ([4., 0.], 1.)]);
+        assert!(circle.contains(&([2., 0.], 3.)));
+        assert!(circle.contains(&([2., 1.], 1.)));
+        assert!(!circle.contains(&([2., 1.], 2.5)));
+        assert!(!DiskCircle2D::<Disk<f64>>::None.contains(&([0., 0.], 0.)));
+    }
+
+    #[test]
+    fn f32() {
+        assert_eq!(
+            DiskCircle2D::new(&[([0f32, 0.], 1.), ([4., 0.], 1.)]).disk(),
+            Some(Disk::new([2., 0.], 3.))
+        );
+    }
+}
@@ -0,0 +1,285 @@
+use crate::{
+    algorithm::{welzl, EnclosingCircleResult, Indexed},
+    circle::Circle2D,
+    geometry::point::PointLike,
+    predicates::{
+        in_circle::{DefaultInCircle, InCircle},
+        orientation::{DefaultOrientation, Orientation},
+    },
+    random::SplitMix64,
+};
+
+/// Keeps a set of points together with their smallest enclosing circle, and supports both insertions and deletions.
+///
+/// Every inserted point is assigned an id, which stays valid until the point is removed (ids of removed points are reused).
+/// - Inserting a point that is contained by the current circle, and removing a point that does not span the current circle, take `O(1)` time, the circle does not change.
+/// - Inserting a point outside of the current circle solves the problem with this point fixed as spanning point (see [`crate::IncrementalEnclosingCircle`]), and removing a spanning point recomputes the circle from the remaining points. Both take expected `O(n)` time.
+///
+/// Each insertion outside of the current circle copies and reshuffles all `n` points, so inserting points in an order that keeps enlarging the circle (e.g., sorted by distance from the first point) costs `O(n)` per insertion.
+/// As at most three points span the circle, deleting a random point triggers a recomputation with probability at most `3/n` only, i.e., the expected time per deletion is `O(1)`.
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::{Circle2D, DynamicEnclosingCircle};
+///
+/// let mut dynamic = DynamicEnclosingCircle::new();
+/// let a = dynamic.insert([0., 0.]);
+/// let b = dynamic.insert([4., 0.]);
+/// dynamic.insert([1., 1.]);
+/// assert!(dynamic.circle().equals(&Circle2D::new(&[[0., 0.], [4., 0.]])));
+///
+/// dynamic.remove(b);
+/// assert!(dynamic.circle().equals(&Circle2D::new(&[[0., 0.], [1., 1.]])));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DynamicEnclosingCircle<Point> {
+    slots: Vec<Option<Point>>,
+    free: Vec<usize>,
+    len: usize,
+    circle: Circle2D<Point>,
+    support_ids: Vec<usize>,
+    seed: u64,
+}
+
+impl<Point> DynamicEnclosingCircle<Point> {
+    /// Creates an empty structure.
+    pub fn new() -> Self {
+        DynamicEnclosingCircle {
+            slots: Vec::new(),
+            free: Vec::new(),
+            len: 0,
+            circle: Circle2D::None,
+            support_ids: Vec::new(),
+            seed: 0,
+        }
+    }
+
+    /// The smallest enclosing circle of all points currently in the structure.
+    pub fn circle(&self) -> &Circle2D<Point> {
+        &self.circle
+    }
+
+    /// The ids of the points that span [`DynamicEnclosingCircle::circle`], in the same order as they appear in the circle's variant.
+    pub fn support_ids(&self) -> &[usize] {
+        &self.support_ids
+    }
+
+    /// The point with the given id, `None` if there is no such point.
+    pub fn get(&self, id: usize) -> Option<&Point> {
+        self.slots.get(id).and_then(|slot| slot.as_ref())
+    }
+
+    /// Iterates over all points currently in the structure, together with their ids.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &Point)> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(id, slot)| slot.as_ref().map(|point| (id, point)))
+    }
+
+    /// The number of points currently in the structure.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the structure contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Inserts a point, updates the circle if the point is not contained by it, and returns the id of the point.
+    pub fn insert<C>(&mut self, point: Point) -> usize
+    where
+        Point: PartialEq + PointLike<C, 2> + Copy,
+        C: PartialEq,
+        DefaultInCircle: InCircle<C>,
+        DefaultOrientation: Orientation<C>,
+    {
+        self.insert_with_predicate::<DefaultInCircle, C>(point)
+    }
+
+    /// See [`DynamicEnclosingCircle::insert`]. Additionally, supports a custom [`InCircle`] predicate.
+    pub fn insert_with_predicate<InCirclePredicate, C>(&mut self, point: Point) -> usize
+    where
+        Point: PartialEq + PointLike<C, 2> + Copy,
+        InCirclePredicate: InCircle<C>,
+        C: PartialEq,
+        DefaultOrientation: Orientation<C>,
+    {
+        let id = match self.free.pop() {
+            Some(id) => id,
+            None => {
+                self.slots.push(None);
+                self.slots.len() - 1
+            }
+        };
+        if !self
+            .circle
+            .contains_with_predicate::<Point, InCirclePredicate, C>(&point)
+        {
+            let fixed = Indexed { index: id, point };
+            let mut points = self.indexed();
+            self.seed += 1;
+            SplitMix64::new(self.seed).shuffle(&mut points);
+            self.update::<InCirclePredicate, C>(points, Vec::from([fixed]));
+        }
+        self.slots[id] = Some(point);
+        self.len += 1;
+        id
+    }
+
+    /// Removes the point with the given id and returns it, recomputes the circle if the point spans it. `None` if there is no such point.
+    pub fn remove<C>(&mut self, id: usize) -> Option<Point>
+    where
+        Point: PartialEq + PointLike<C, 2> + Copy,
+        C: PartialEq,
+        DefaultInCircle: InCircle<C>,
+        DefaultOrientation: Orientation<C>,
+    {
+        self.remove_with_predicate::<DefaultInCircle, C>(id)
+    }
+
+    /// See [`DynamicEnclosingCircle::remove`]. Additionally, supports a custom [`InCircle`] predicate.
+    pub fn remove_with_predicate<InCirclePredicate, C>(&mut self, id: usize) -> Option<Point>
+    where
+        Point: PartialEq + PointLike<C, 2> + Copy,
+        InCirclePredicate: InCircle<C>,
+        C: PartialEq,
+        DefaultOrientation: Orientation<C>,
+    {
+        let point = self.slots.get_mut(id)?.take()?;
+        self.free.push(id);
+        self.len -= 1;
+        if self.support_ids.contains(&id) {
+            let mut points = self.indexed();
+            self.seed += 1;
+            SplitMix64::new(self.seed).shuffle(&mut points);
+            self.update::<InCirclePredicate, C>(points, Vec::new());
+        }
+        Some(point)
+    }
+
+    fn indexed(&self) -> Vec<Indexed<Point>>
+    where
+        Point: Copy,
+    {
+        self.iter()
+            .map(|(index, point)| Indexed {
+                index,
+                point: *point,
+            })
+            .collect()
+    }
+
+    /// Solves the problem for the given points with the given spanning points fixed.
+    fn update<InCirclePredicate, C>(
+        &mut self,
+        points: Vec<Indexed<Point>>,
+        fixed: Vec<Indexed<Point>>,
+    ) where
+        Point: PartialEq + PointLike<C, 2> + Copy,
+        InCirclePredicate: InCircle<C>,
+        C: PartialEq,
+        DefaultOrientation: Orientation<C>,
+    {
+        let circle = welzl(
+            points,
+            fixed,
+            3,
+            |r| Circle2D::new(r),
+            |circle, element| {
+                !circle.contains_with_predicate::<Indexed<Point>, InCirclePredicate, C>(element)
+            },
+        );
+        let result: EnclosingCircleResult<Point> = circle.into();
+        self.circle = result.circle;
+        self.support_ids = result.support_indices;
+    }
+}
+
+impl<Point> Default for DynamicEnclosingCircle<Point> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::algorithm::smallest_enclosing_circle;
+
+    #[test]
+    fn empty() {
+        let mut dynamic = DynamicEnclosingCircle::<[f64; 2]>::default();
+        assert_eq!(dynamic.circle(), &Circle2D::None);
+        assert_eq!(dynamic.remove(0), None);
+        assert!(dynamic.is_empty());
+    }
+
+    #[test]
+    fn remove_all() {
+        let mut dynamic = DynamicEnclosingCircle::new();
+        let ids: Vec<usize> = [[0., 0.], [1., 0.], [0., 1.]]
+            .into_iter()
+            .map(|p| dynamic.insert(p))
+            .collect();
+        for id in ids {
+            assert!(dynamic.remove(id).is_some());
+            assert_eq!(dynamic.remove(id), None);
+        }
+        assert_eq!(dynamic.circle(), &Circle2D::None);
+        assert!(dynamic.support_ids().is_empty());
+    }
+
+    #[test]
+    fn ids_are_reused() {
+        let mut dynamic = DynamicEnclosingCircle::new();
+        let a = dynamic.insert([0., 0.]);
+        dynamic.insert([1., 0.]);
+        dynamic.remove(a);
+        assert_eq!(dynamic.insert([2., 0.]), a);
+        assert_eq!(dynamic.get(a), Some(&[2., 0.]));
+        assert!(dynamic
+            .circle()
+            .equals(&Circle2D::new(&[[1., 0.], [2., 0.]])));
+    }
+
+    #[test]
+    fn non_support_removal_keeps_circle() {
+        let mut dynamic = DynamicEnclosingCircle::new();
+        dynamic.insert([0., 0.]);
+        dynamic.insert([4., 0.]);
+        let inner = dynamic.insert([2., 1.]);
+        let circle = *dynamic.circle();
+        dynamic.remove(inner);
+        assert_eq!(dynamic.circle(), &circle);
+    }
+
+    #[test]
+    fn matches_batch() {
+        let mut rng = SplitMix64::new(23);
+        let mut dynamic = DynamicEnclosingCircle::new();
+        let mut ids = Vec::new();
+        for step in 0..2000 {
+            if ids.is_empty() || rng.next_f64() < 0.6 {
+                ids.push(dynamic.insert([rng.next_f64(), rng.next_f64()]));
+            } else {
+                let i = (rng.next_f64() * ids.len() as f64) as usize;
+                dynamic.remove(ids.swap_remove(i));
+            }
+            if step % 100 == 0 {
+                let points: Vec<[f64; 2]> = dynamic.iter().map(|(_, p)| *p).collect();
+                assert!(dynamic.circle().equals(&smallest_enclosing_circle(points)));
+                let spanning: Vec<[f64; 2]> = dynamic
+                    .support_ids()
+                    .iter()
+                    .map(|id| *dynamic.get(*id).unwrap())
+                    .collect();
+                assert!(dynamic.circle().equals(&Circle2D::new(&spanning)));
+            }
+        }
+        assert_eq!(dynamic.len(), ids.len());
+    }
+}
@@ -0,0 +1,598 @@
+use std::{cmp::Ordering, f64::consts::PI};
+
+use crate::{
+    geometry::point::PointLike,
+    predicates::orientation::{DefaultOrientation, Orientation, OrientationState},
+};
+
+/// Relative tolerance of [`Ellipse::contains`], absorbs the rounding errors of the (ill-conditioned) conic computations.
+const TOLERANCE: f64 = 1e-9;
+
+/// An ellipse given by its center, its semi-axes (major first), and its rotation, i.e., the angle between the x-axis and the major axis (in radians, within `(-π/2, π/2]`).
+///
+/// Unlike circles, ellipses are only supported for `f64` coordinates: the conic computations involve square roots and trigonometric functions, and are inexact anyway.
+///
+/// Degenerate ellipses are allowed: a minor semi-axis of zero describes a line segment, and two zero semi-axes describe a single point.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Ellipse {
+    pub center: [f64; 2],
+    pub semi_axes: [f64; 2],
+    pub rotation: f64,
+}
+
+impl Ellipse {
+    /// Computes the area of the ellipse.
+    pub fn area(&self) -> f64 {
+        PI * self.semi_axes[0] * self.semi_axes[1]
+    }
+
+    /// Checks whether the given point is contained by the ellipse, i.e., whether it lies on *or* inside the ellipse, up to a small relative tolerance.
+    ///
+    /// ```
+    /// use smallest_enclosing_circle::ellipse::Ellipse;
+    ///
+    /// let ellipse = Ellipse { center: [0., 0.], semi_axes: [2., 1.], rotation: 0. };
+    /// assert!(ellipse.contains(&[2., 0.]));
+    /// assert!(!ellipse.contains(&[0., 1.5]));
+    /// ```
+    pub fn contains(&self, point: &impl PointLike<f64, 2>) -> bool {
+        let [u, v] = self.local(point.coordinates());
+        let [a, b] = self.semi_axes;
+        if b <= TOLERANCE * a {
+            // Line segment (or point)
+            u.abs() <= a * (1. + TOLERANCE) && v.abs() <= TOLERANCE * a
+        } else {
+            (u / a).powi(2) + (v / b).powi(2) <= 1. + TOLERANCE
+        }
+    }
+
+    /// Transforms the given point into the coordinate system of the ellipse (major axis along the first coordinate).
+    fn local(&self, point: [f64; 2]) -> [f64; 2] {
+        let [dx, dy] = [point[0] - self.center[0], point[1] - self.center[1]];
+        let (sin, cos) = self.rotation.sin_cos();
+        [cos * dx + sin * dy, -sin * dx + cos * dy]
+    }
+
+    /// Constructs the ellipse `(x - center)ᵀ M (x - center) = 1` from a symmetric positive definite matrix `M = [[m00, m01], [m01, m11]]`.
+    fn from_matrix(center: [f64; 2], m: [f64; 3]) -> Option<Self> {
+        let [m00, m01, m11] = m;
+        let mean = (m00 + m11) / 2.;
+        let deviation = ((m00 - m11) / 2.).hypot(m01);
+        let [small, large] = [mean - deviation, mean + deviation];
+        if small.partial_cmp(&0.) != Some(Ordering::Greater) || !large.is_finite() {
+            return None;
+        }
+        let rotation = normalize_angle(0.5 * (2. * m01).atan2(m00 - m11) + PI / 2.);
+        Some(Ellipse {
+            center,
+            semi_axes: [1. / small.sqrt(), 1. / large.sqrt()],
+            rotation,
+        })
+    }
+
+    /// Constructs the ellipse given by the conic `a x² + b xy + c y² + d x + e y + f = 0`. `None` if the conic is not a (real, non-degenerate) ellipse.
+    fn from_conic(conic: [f64; 6]) -> Option<Self> {
+        let [a, b, c, d, e, f] = conic;
+        let det = determinant2(conic);
+        if det.partial_cmp(&0.) != Some(Ordering::Greater) {
+            return None;
+        }
+        let center = [
+            (b * e / 2. - c * d) / (2. * det),
+            (b * d / 2. - a * e) / (2. * det),
+        ];
+        let value = f + (d * center[0] + e * center[1]) / 2.;
+        let scale = -1. / value;
+        Self::from_matrix(center, [a * scale, b / 2. * scale, c * scale])
+    }
+
+    /// Maps an ellipse from normalized coordinates back, see [`Normalization`].
+    fn denormalize(self, normalization: &Normalization) -> Self {
+        Ellipse {
+            center: normalization.denormalize(self.center),
+            semi_axes: self.semi_axes.map(|s| s * normalization.scale),
+            rotation: self.rotation,
+        }
+    }
+}
+
+/// Represents the result of [`crate::algorithm::smallest_enclosing_ellipse`], an ellipse defined by up to five points that are located on its boundary (points that *span* the ellipse).
+///
+/// As for [`crate::Circle2D`], only the spanning points are stored, and the actual [`Ellipse`] is computed on demand with [`Ellipse2D::ellipse`]:
+/// - no points: no ellipse,
+/// - one point: the point itself (both semi-axes zero),
+/// - two points: the line segment between them (minor semi-axis zero),
+/// - three points: the Steiner circumellipse of the triangle (the smallest ellipse through three points),
+/// - four points: the smallest ellipse through the four points, approximated numerically (the area is sampled at 256 angles of the pencil of conics through them, and the minimum is refined by bisection or golden-section search),
+/// - five points: the unique conic through the five points.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Ellipse2D<Point> {
+    support: Vec<Point>,
+}
+
+impl<Point> Ellipse2D<Point> {
+    /// Creates a new [`Ellipse2D`] from up to five points.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if more than five points are given.
+    pub fn new(points: &[Point]) -> Self
+    where
+        Point: Copy,
+    {
+        if points.len() > 5 {
+            panic!(
+                "an ellipse is spanned by at most 5 points, but {} points were supplied",
+                points.len()
+            );
+        }
+        Ellipse2D {
+            support: points.to_vec(),
+        }
+    }
+
+    /// The points that span the ellipse.
+    pub fn support(&self) -> &[Point] {
+        &self.support
+    }
+
+    /// Computes the smallest ellipse that has all spanning points on its boundary. `None` for an ellipse without spanning points.
+    ///
+    /// If no such ellipse exists (numerically), e.g., for collinear points, or for four points that are not in convex position, the smallest ellipse spanned by a subset of the points that encloses all of them is returned instead.
+    ///
+    /// ```
+    /// use smallest_enclosing_circle::ellipse::Ellipse2D;
+    ///
+    /// let ellipse = Ellipse2D::new(&[[-2., 0.], [2., 0.], [0., 1.], [0., -1.], [1.6, 0.6]]).ellipse().unwrap();
+    /// assert!((ellipse.semi_axes[0] - 2.).abs() < 1e-9);
+    /// assert!((ellipse.semi_axes[1] - 1.).abs() < 1e-9);
+    /// ```
+    pub fn ellipse(&self) -> Option<Ellipse>
+    where
+        Point: PointLike<f64, 2>,
+    {
+        let points: Vec<[f64; 2]> = self.support.iter().map(|p| p.coordinates()).collect();
+        smallest_ellipse_through(&points)
+    }
+
+    /// Checks whether the given point is contained by the ellipse. See [`Ellipse::contains`].
+    pub fn contains(&self, point: &impl PointLike<f64, 2>) -> bool
+    where
+        Point: PointLike<f64, 2>,
+    {
+        self.ellipse().is_some_and(|e| e.contains(point))
+    }
+}
+
+/// Computes the smallest ellipse that has all given points on its boundary, falls back to the smallest enclosing ellipse spanned by a subset.
+fn smallest_ellipse_through(points: &[[f64; 2]]) -> Option<Ellipse> {
+    let exact = match points.len() {
+        0 => return None,
+        1 => Some(Ellipse {
+            center: points[0],
+            semi_axes: [0., 0.],
+            rotation: 0.,
+        }),
+        2 => Some(segment(points[0], points[1])),
+        _ => {
+            let normalization = Normalization::new(points);
+            let normalized: Vec<[f64; 2]> =
+                points.iter().map(|p| normalization.normalize(*p)).collect();
+            match normalized.len() {
+                3 => steiner_circumellipse(&normalized),
+                4 => smallest_ellipse_through_four(&normalized),
+                _ => ellipse_through_five(&normalized),
+            }
+            .map(|e| e.denormalize(&normalization))
+        }
+    };
+    exact
+        .filter(|e| e.semi_axes.iter().chain(&e.center).all(|v| v.is_finite()))
+        .filter(|e| points.iter().all(|p| e.contains(p)))
+        .or_else(|| {
+            (0..points.len())
+                .filter_map(|skipped| {
+                    let subset: Vec<[f64; 2]> = (0..points.len())
+                        .filter(|i| *i != skipped)
+                        .map(|i| points[i])
+                        .collect();
+                    smallest_ellipse_through(&subset)
+                })
+                .filter(|e| points.iter().all(|p| e.contains(p)))
+                .min_by(|a, b| {
+                    a.area()
+                        .total_cmp(&b.area())
+                        .then(a.semi_axes[0].total_cmp(&b.semi_axes[0]))
+                })
+        })
+}
+
+fn segment(a: [f64; 2], b: [f64; 2]) -> Ellipse {
+    let [dx, dy] = [b[0] - a[0], b[1] - a[1]];
+    Ellipse {
+        center: [(a[0] + b[0]) / 2., (a[1] + b[1]) / 2.],
+        semi_axes: [dx.hypot(dy) / 2., 0.],
+        rotation: normalize_angle(dy.atan2(dx)),
+    }
+}
+
+/// The Steiner circumellipse of a triangle is centered at the centroid `g`, and given by `M = 3/2 S⁻¹` with the scatter matrix `S = Σ (p - g)(p - g)ᵀ`.
+fn steiner_circumellipse(points: &[[f64; 2]]) -> Option<Ellipse> {
+    let g = [0, 1].map(|i| points.iter().map(|p| p[i]).sum::<f64>() / 3.);
+    let [mut sxx, mut sxy, mut syy] = [0.; 3];
+    for p in points {
+        let [dx, dy] = [p[0] - g[0], p[1] - g[1]];
+        sxx += dx * dx;
+        sxy += dx * dy;
+        syy += dy * dy;
+    }
+    let det = sxx * syy - sxy * sxy;
+    if det.partial_cmp(&0.) != Some(Ordering::Greater) {
+        return None;
+    }
+    let factor = 1.5 / det;
+    Ellipse::from_matrix(g, [syy * factor, -sxy * factor, sxx * factor])
+}
+
+/// Among all conics through the four points (a pencil spanned by the two pairs of opposite sides of the quadrilateral), finds the ellipse of minimum area. `None` if the points are not in convex position.
+///
+/// The result is approximate: the minimum is not solved for in closed form (a cubic in the pencil parameter), but located by sampling the area at 256 angles, and refined by bisection on the slope (golden-section search on the area if the slope does not change its sign).
+/// The refinement converges to the rounding errors of the conic computations, which [`TOLERANCE`] absorbs.
+fn smallest_ellipse_through_four(points: &[[f64; 2]]) -> Option<Ellipse> {
+    let orientation = |a: usize, b: usize, c: usize| {
+        DefaultOrientation::orientation(&points[a], &points[b], &points[c])
+    };
+    let crosses = |[a, b]: [usize; 2], [c, d]: [usize; 2]| {
+        let sides = |x, y| {
+            let [o1, o2] = [orientation(x, y, c), orientation(x, y, d)];
+            o1 != OrientationState::Collinear && o2 != OrientationState::Collinear && o1 != o2
+        };
+        sides(a, b) && {
+            let [o1, o2] = [orientation(c, d, a), orientation(c, d, b)];
+            o1 != OrientationState::Collinear && o2 != OrientationState::Collinear && o1 != o2
+        }
+    };
+    let pairings = [([0, 1], [2, 3]), ([0, 2], [1, 3]), ([0, 3], [1, 2])];
+    let diagonals = pairings.iter().position(|(a, b)| crosses(*a, *b))?;
+    let [first, second] = match diagonals {
+        0 => [pairings[1], pairings[2]],
+        1 => [pairings[0], pairings[2]],
+        _ => [pairings[0], pairings[1]],
+    }
+    .map(|(a, b)| {
+        line_pair(
+            line(points[a[0]], points[a[1]]),
+            line(points[b[0]], points[b[1]]),
+        )
+    });
+
+    let conic = |theta: f64| -> [f64; 6] {
+        let (sin, cos) = theta.sin_cos();
+        std::array::from_fn(|i| cos * first[i] + sin * second[i])
+    };
+    let derivative = |theta: f64| -> [f64; 6] {
+        let (sin, cos) = theta.sin_cos();
+        std::array::from_fn(|i| -sin * first[i] + cos * second[i])
+    };
+    // Area up to the factor π, infinite for non-ellipses.
+    let area = |theta: f64| {
+        let conic = conic(theta);
+        let det2 = determinant2(conic);
+        if det2.partial_cmp(&0.) != Some(Ordering::Greater) {
+            return f64::INFINITY;
+        }
+        determinant3(conic).abs() / det2.powf(1.5)
+    };
+    // Derivative of the logarithmic area, up to a positive factor: det3' det2 - 3/2 det2' det3.
+    let slope = |theta: f64| {
+        let [conic, derivative] = [conic(theta), derivative(theta)];
+        let [a, b, c, ..] = conic;
+        let [da, db, dc, ..] = derivative;
+        let det2 = determinant2(conic);
+        let ddet2 = da * c + a * dc - b * db / 2.;
+        let matrix = symmetric(conic);
+        let dmatrix = symmetric(derivative);
+        let ddet3 = (0..3)
+            .flat_map(|i| (0..3).map(move |j| (i, j)))
+            .map(|(i, j)| cofactor(&matrix, i, j) * dmatrix[i][j])
+            .sum::<f64>();
+        let det3 = determinant3(conic);
+        (ddet3 * det2 - 1.5 * ddet2 * det3) * det3.signum()
+    };
+
+    const SAMPLES: usize = 256;
+    let step = PI / SAMPLES as f64;
+    let best = (0..SAMPLES)
+        .map(|i| i as f64 * step)
+        .min_by(|a, b| area(*a).total_cmp(&area(*b)))?;
+    if !area(best).is_finite() {
+        return None;
+    }
+    // The area is unimodal on the interval of ellipses, its minimum is the root of the slope.
+    let [mut low, mut high] = [best - step, best + step];
+    if slope(low) < 0. && slope(high) > 0. {
+        for _ in 0..100 {
+            let middle = (low + high) / 2.;
+            if slope(middle) < 0. {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+    } else {
+        // Golden-section search as fallback.
+        let ratio = (5f64.sqrt() - 1.) / 2.;
+        for _ in 0..100 {
+            let x1 = high - ratio * (high - low);
+            let x2 = low + ratio * (high - low);
+            if area(x1) < area(x2) {
+                high = x2;
+            } else {
+                low = x1;
+            }
+        }
+    }
+    Ellipse::from_conic(conic((low + high) / 2.))
+}
+
+/// The symmetric 3x3 matrix of the conic `a x² + b xy + c y² + d x + e y + f = 0`.
+fn symmetric(conic: [f64; 6]) -> [[f64; 3]; 3] {
+    let [a, b, c, d, e, f] = conic;
+    [
+        [a, b / 2., d / 2.],
+        [b / 2., c, e / 2.],
+        [d / 2., e / 2., f],
+    ]
+}
+
+fn cofactor(matrix: &[[f64; 3]; 3], i: usize, j: usize) -> f64 {
+    let [r0, r1] = [(i + 1) % 3, (i + 2) % 3];
+    let [c0, c1] = [(j + 1) % 3, (j + 2) % 3];
+    matrix[r0][c0] * matrix[r1][c1] - matrix[r0][c1] * matrix[r1][c0]
+}
+
+/// The determinant of the quadratic part of the conic, positive for ellipses.
+fn determinant2(conic: [f64; 6]) -> f64 {
+    conic[0] * conic[2] - conic[1] * conic[1] / 4.
+}
+
+/// The determinant of the symmetric matrix of the conic, zero for degenerate conics.
+fn determinant3(conic: [f64; 6]) -> f64 {
+    let matrix = symmetric(conic);
+    (0..3).map(|j| matrix[0][j] * cofactor(&matrix, 0, j)).sum()
+}
+
+/// The conic through five points, its coefficients are the signed 5x5 minors of the matrix with rows `[x², xy, y², x, y, 1]`.
+fn ellipse_through_five(points: &[[f64; 2]]) -> Option<Ellipse> {
+    let rows: Vec<[f64; 6]> = points
+        .iter()
+        .map(|&[x, y]| [x * x, x * y, y * y, x, y, 1.])
+        .collect();
+    let conic: [f64; 6] = std::array::from_fn(|skipped| {
+        let mut matrix: Vec<Vec<f64>> = rows
+            .iter()
+            .map(|row| (0..6).filter(|i| *i != skipped).map(|i| row[i]).collect())
+            .collect();
+        let sign = if skipped % 2 == 0 { 1. } else { -1. };
+        sign * determinant(&mut matrix)
+    });
+    Ellipse::from_conic(conic)
+}
+
+/// Computes the determinant with Gaussian elimination (partial pivoting).
+fn determinant(matrix: &mut [Vec<f64>]) -> f64 {
+    let n = matrix.len();
+    let mut det = 1.;
+    for k in 0..n {
+        let pivot = (k..n)
+            .max_by(|&i, &j| matrix[i][k].abs().total_cmp(&matrix[j][k].abs()))
+            .unwrap();
+        if matrix[pivot][k] == 0. {
+            return 0.;
+        }
+        if pivot != k {
+            matrix.swap(k, pivot);
+            det = -det;
+        }
+        det *= matrix[k][k];
+        let (upper, lower) = matrix.split_at_mut(k + 1);
+        let pivot_row = &upper[k];
+        for row in lower {
+            let factor = row[k] / pivot_row[k];
+            for (value, pivot_value) in row[k..].iter_mut().zip(&pivot_row[k..]) {
+                *value -= factor * pivot_value;
+            }
+        }
+    }
+    det
+}
+
+/// The line `α x + β y + γ = 0` through two points.
+fn line(p: [f64; 2], q: [f64; 2]) -> [f64; 3] {
+    [p[1] - q[1], q[0] - p[0], p[0] * q[1] - q[0] * p[1]]
+}
+
+/// The (degenerate) conic that is the union of two lines.
+fn line_pair(l: [f64; 3], m: [f64; 3]) -> [f64; 6] {
+    [
+        l[0] * m[0],
+        l[0] * m[1] + l[1] * m[0],
+        l[1] * m[1],
+        l[0] * m[2] + l[2] * m[0],
+        l[1] * m[2] + l[2] * m[1],
+        l[2] * m[2],
+    ]
+}
+
+/// Maps angles to `(-π/2, π/2]`, the rotation of an ellipse is only defined up to multiples of π.
+fn normalize_angle(angle: f64) -> f64 {
+    let mut angle = angle % PI;
+    if angle > PI / 2. {
+        angle -= PI;
+    } else if angle <= -PI / 2. {
+        angle += PI;
+    }
+    angle
+}
+
+/// Translates points to their centroid and scales them to unit size, improves the conditioning of the conic computations.
+struct Normalization {
+    offset: [f64; 2],
+    scale: f64,
+}
+
+impl Normalization {
+    fn new(points: &[[f64; 2]]) -> Self {
+        let n = points.len() as f64;
+        let offset = [0, 1].map(|i| points.iter().map(|p| p[i]).sum::<f64>() / n);
+        let scale = points
+            .iter()
+            .flat_map(|p| [p[0] - offset[0], p[1] - offset[1]])
+            .fold(0., |max: f64, v| max.max(v.abs()));
+        Normalization {
+            offset,
+            scale: if scale > 0. { scale } else { 1. },
+        }
+    }
+
+    fn normalize(&self, p: [f64; 2]) -> [f64; 2] {
+        [0, 1].map(|i| (p[i] - self.offset[i]) / self.scale)
+    }
+
+    fn denormalize(&self, p: [f64; 2]) -> [f64; 2] {
+        [0, 1].map(|i| p[i] * self.scale + self.offset[i])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(ellipse: Ellipse, center: [f64; 2], semi_axes: [f64; 2]) {
+        for i in 0..2 {
+            assert!((ellipse.center[i] - center[i]).abs() < 1e-9, "{ellipse:?}");
+            assert!(
+                (ellipse.semi_axes[i] - semi_axes[i]).abs() < 1e-9,
+                "{ellipse:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn none() {
+        assert_eq!(Ellipse2D::<[f64; 2]>::new(&[]).ellipse(), None);
+    }
+
+    #[test]
+    fn one() {
+        assert_close(
+            Ellipse2D::new(&[[1., 2.]]).ellipse().unwrap(),
+            [1., 2.],
+            [0., 0.],
+        );
+    }
+
+    #[test]
+    fn two() {
+        let ellipse = Ellipse2D::new(&[[0., 0.], [2., 2.]]).ellipse().unwrap();
+        assert_close(ellipse, [1., 1.], [2f64.sqrt(), 0.]);
+        assert!((ellipse.rotation - PI / 4.).abs() < 1e-12);
+        assert!(ellipse.contains(&[0.5, 0.5]));
+        assert!(!ellipse.contains(&[0.5, 0.6]));
+        assert!(!ellipse.contains(&[2.1, 2.1]));
+    }
+
+    #[test]
+    fn three_equilateral() {
+        let h = 3f64.sqrt() / 2.;
+        let ellipse = Ellipse2D::new(&[[1., 0.], [-0.5, h], [-0.5, -h]])
+            .ellipse()
+            .unwrap();
+        assert_close(ellipse, [0., 0.], [1., 1.]);
+    }
+
+    #[test]
+    fn three_collinear() {
+        let ellipse = Ellipse2D::new(&[[0., 0.], [3., 0.], [1., 0.]])
+            .ellipse()
+            .unwrap();
+        assert_close(ellipse, [1.5, 0.], [1.5, 0.]);
+    }
+
+    #[test]
+    fn four_rectangle() {
+        let ellipse = Ellipse2D::new(&[[-2., -1.], [2., -1.], [2., 1.], [-2., 1.]])
+            .ellipse()
+            .unwrap();
+        assert_close(ellipse, [0., 0.], [2. * 2f64.sqrt(), 2f64.sqrt()]);
+        assert!(ellipse.rotation.abs() < 1e-6);
+    }
+
+    #[test]
+    fn four_rotated_square() {
+        let ellipse = Ellipse2D::new(&[[0., 1.], [1., 0.], [-1., 0.], [0., -1.]])
+            .ellipse()
+            .unwrap();
+        assert_close(ellipse, [0., 0.], [1., 1.]);
+    }
+
+    #[test]
+    fn four_not_convex() {
+        let points = [[0., 0.], [4., 0.], [0., 4.], [1., 1.]];
+        let ellipse = Ellipse2D::new(&points).ellipse().unwrap();
+        assert!(points.iter().all(|p| ellipse.contains(p)));
+    }
+
+    #[test]
+    fn five() {
+        let points = [
+            [3., 1.],
+            [-3., 1.],
+            [1., 3.],
+            [1., -1.],
+            [-1., 1. + 4. / 3. * 2f64.sqrt()],
+        ];
+        let ellipse = Ellipse2D::new(&points).ellipse().unwrap();
+        assert!(points.iter().all(|p| ellipse.contains(p)));
+        assert_eq!(
+            Ellipse2D::new(&[[0., 0.], [1., 0.], [2., 0.], [3., 0.], [4., 0.]])
+                .ellipse()
+                .map(|e| e.semi_axes),
+            Some([2., 0.])
+        );
+    }
+
+    #[test]
+    fn rotated() {
+        let ellipse = Ellipse {
+            center: [1., -1.],
+            semi_axes: [3., 1.],
+            rotation: 0.5,
+        };
+        let (sin, cos) = ellipse.rotation.sin_cos();
+        let points: Vec<[f64; 2]> = [0.3, 1.4, 2.9, 4.0, 5.5]
+            .iter()
+            .map(|t: &f64| {
+                let [u, v] = [3. * t.cos(), t.sin()];
+                [1. + cos * u - sin * v, -1. + sin * u + cos * v]
+            })
+            .collect();
+        let computed = Ellipse2D::new(&points).ellipse().unwrap();
+        assert_close(computed, ellipse.center, ellipse.semi_axes);
+        assert!((computed.rotation - ellipse.rotation).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "at most 5 points")]
+    fn too_many() {
+        Ellipse2D::new(&[[0., 0.]; 6]);
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!(normalize_angle(PI), 0.);
+        assert_eq!(normalize_angle(PI / 2.), PI / 2.);
+        assert_eq!(normalize_angle(-PI / 2.), PI / 2.);
+        assert!((normalize_angle(3. * PI / 4.) + PI / 4.).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,48 @@
+use std::fmt::{Display, Formatter};
+
+/// Describes why a circle could not be constructed, see e.g. [`crate::Circle2D::try_new`] or [`crate::geometry::circumcircle::try_circumcircle2d`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum CircleError {
+    /// A circle is spanned by at most three points, but more were supplied. Contains the number of supplied points.
+    TooManyPoints(usize),
+    /// The supplied points are collinear (and distinct), so there is no circle passing through all of them.
+    Collinear,
+    /// At least one of the supplied coordinates is NaN or infinite.
+    NonFinite,
+}
+
+impl Display for CircleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CircleError::TooManyPoints(n) => write!(
+                f,
+                "a circle is spanned by at most 3 points, but {} points were supplied",
+                n
+            ),
+            CircleError::Collinear => write!(f, "there is no circle passing through collinear points"),
+            CircleError::NonFinite => write!(f, "coordinates must not be NaN or infinite"),
+        }
+    }
+}
+
+impl std::error::Error for CircleError {}
+
+/// Returned by [`crate::validation::smallest_enclosing_circle_validated`] if the input contains points with NaN or infinite coordinates and [`crate::validation::ValidationPolicy::Reject`] is used.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct NonFinitePointsError {
+    /// Indices (positions in the input iterator) of all points with at least one NaN or infinite coordinate, in ascending order.
+    pub indices: Vec<usize>,
+}
+
+impl Display for NonFinitePointsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} point(s) with NaN or infinite coordinates at indices {:?}",
+            self.indices.len(),
+            self.indices
+        )
+    }
+}
+
+impl std::error::Error for NonFinitePointsError {}
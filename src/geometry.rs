@@ -1,3 +1,7 @@
 pub mod point;
+pub mod apollonius;
 pub mod circumcircle;
-mod num;
\ No newline at end of file
+pub mod interval;
+pub mod num;
+pub mod weighted;
+
@@ -0,0 +1,211 @@
+use num::traits::{float::TotalOrder, real::Real};
+
+use crate::geometry::num::ConstTwo;
+
+/// Computes the center and radius of the smallest circle that encloses the two disks `a` and `b` (given as center and radius). If neither disk contains the other, the circle touches both disks from the outside, and its center lies on the line through their centers. Otherwise, the larger disk is returned.
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::geometry::apollonius::enclosing_circle_of_two_disks2d;
+///
+/// let circle = enclosing_circle_of_two_disks2d(([0., 0.], 1.), ([4., 0.], 1.));
+/// assert_eq!(circle, ([2., 0.], 3.));
+/// ```
+pub fn enclosing_circle_of_two_disks2d<C>(a: ([C; 2], C), b: ([C; 2], C)) -> ([C; 2], C)
+where
+    C: Real + ConstTwo,
+{
+    let (ca, ra) = a;
+    let (cb, rb) = b;
+    let distance = (cb[0] - ca[0]).hypot(cb[1] - ca[1]);
+    if distance + rb <= ra {
+        return a;
+    }
+    if distance + ra <= rb {
+        return b;
+    }
+    let radius = (distance + ra + rb) / C::TWO;
+    let t = (radius - ra) / distance;
+    let center = [ca[0] + t * (cb[0] - ca[0]), ca[1] + t * (cb[1] - ca[1])];
+    (center, radius)
+}
+
+/// Computes the center and radius of the smallest circle that encloses the three disks `a`, `b`, and `c` (given as center and radius) and touches all of them, i.e., a solution of Apollonius' problem with all three disks internally tangent.
+///
+/// The tangency conditions `|center - center_i| = radius - radius_i` are quadratic, but their pairwise differences are linear in the unknowns (center and radius).
+/// The solutions are thus given by a line in the three-dimensional space of circles, which is intersected with the (quadratic) condition of the first disk.
+/// `None` if no such circle exists (e.g., if one disk lies inside another), or if the disks are degenerate (e.g., collinear centers with equal radii).
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::geometry::apollonius::enclosing_circle_of_three_disks2d;
+///
+/// let (center, radius) = enclosing_circle_of_three_disks2d(([-2f64, 0.], 1.), ([2., 0.], 1.), ([0., 2.], 1.)).unwrap();
+/// assert!(center[0].abs() < 1e-12 && center[1].abs() < 1e-12);
+/// assert!((radius - 3.).abs() < 1e-12);
+/// ```
+pub fn enclosing_circle_of_three_disks2d<C>(
+    a: ([C; 2], C),
+    b: ([C; 2], C),
+    c: ([C; 2], C),
+) -> Option<([C; 2], C)>
+where
+    C: Real + ConstTwo + TotalOrder,
+{
+    let (c0, r0) = a;
+    // Unknowns relative to disk `a`: x = center - c0, s = radius - r0, with |x|^2 = s^2.
+    // Each further disk i yields the linear condition m_i · (x, s) = k_i with m_i = (e_i, -f_i).
+    let [m1, m2] = [b, c].map(|(ci, ri)| [ci[0] - c0[0], ci[1] - c0[1], -(ri - r0)]);
+    let [k1, k2] = [m1, m2].map(|m| (m[0] * m[0] + m[1] * m[1] - m[2] * m[2]) / C::TWO);
+
+    // Line of solutions: (x, s) = p + t * n, n is orthogonal to both m_1 and m_2, p is the least-norm solution.
+    let n = cross(m1, m2);
+    let nn = dot(n, n);
+    let scale = dot(m1, m1) * dot(m2, m2);
+    if nn <= scale * C::epsilon() * C::epsilon() {
+        return None;
+    }
+    let [g11, g12, g22] = [dot(m1, m1), dot(m1, m2), dot(m2, m2)];
+    let [l1, l2] = [(k1 * g22 - k2 * g12) / nn, (k2 * g11 - k1 * g12) / nn];
+    let p = [0, 1, 2].map(|i| l1 * m1[i] + l2 * m2[i]);
+
+    // Quadratic condition of disk `a` along the line: |x|^2 - s^2 = 0.
+    let lorentz = |u: [C; 3], v: [C; 3]| u[0] * v[0] + u[1] * v[1] - u[2] * v[2];
+    let [qa, qb, qc] = [lorentz(n, n), C::TWO * lorentz(p, n), lorentz(p, p)];
+    let roots = solve_quadratic(qa, qb, qc);
+
+    let minimum = [C::zero(), -m1[2], -m2[2]]
+        .into_iter()
+        .fold(C::zero(), |max, s| if s > max { s } else { max });
+    let tolerance = C::epsilon().sqrt() * (minimum + dot(p, p).sqrt() + C::one());
+    roots
+        .into_iter()
+        .flatten()
+        .map(|t| [0, 1, 2].map(|i| p[i] + t * n[i]))
+        .filter(|x| x[2] >= minimum - tolerance)
+        .min_by(|u, v| u[2].total_cmp(&v[2]))
+        .map(|x| ([c0[0] + x[0], c0[1] + x[1]], r0 + x[2].max(minimum)))
+}
+
+fn dot<C: Real>(u: [C; 3], v: [C; 3]) -> C {
+    u[0] * v[0] + u[1] * v[1] + u[2] * v[2]
+}
+
+fn cross<C: Real>(u: [C; 3], v: [C; 3]) -> [C; 3] {
+    [
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ]
+}
+
+/// Computes the real roots of `a t^2 + b t + c`, avoiding cancellation. Degenerates gracefully to the linear case.
+pub(crate) fn solve_quadratic<C: Real + ConstTwo>(a: C, b: C, c: C) -> [Option<C>; 2] {
+    let scale = a.abs().max(b.abs()).max(c.abs());
+    if a.abs() <= scale * C::epsilon() {
+        return if b == C::zero() { [None, None] } else { [Some(-c / b), None] };
+    }
+    let discriminant = b * b - C::TWO * C::TWO * a * c;
+    if discriminant < C::zero() {
+        // Tangent solutions suffer from rounding errors, accept slightly negative discriminants.
+        if discriminant >= -(b * b).max((a * c).abs()) * C::epsilon().sqrt() {
+            let t = -b / (C::TWO * a);
+            return [Some(t), None];
+        }
+        return [None, None];
+    }
+    let q = -(b + b.signum() * discriminant.sqrt()) / C::TWO;
+    if q == C::zero() {
+        return [Some(C::zero()), None];
+    }
+    [Some(q / a), Some(c / q)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_encloses(circle: ([f64; 2], f64), disks: &[([f64; 2], f64)]) {
+        for (center, radius) in disks {
+            let distance = (center[0] - circle.0[0]).hypot(center[1] - circle.0[1]);
+            assert!(distance + radius <= circle.1 + 1e-9, "{circle:?} {center:?} {radius}");
+        }
+    }
+
+    fn assert_touches(circle: ([f64; 2], f64), disks: &[([f64; 2], f64)]) {
+        for (center, radius) in disks {
+            let distance = (center[0] - circle.0[0]).hypot(center[1] - circle.0[1]);
+            assert!((distance + radius - circle.1).abs() < 1e-9, "{circle:?} {center:?} {radius}");
+        }
+    }
+
+    mod two {
+        use super::*;
+
+        #[test]
+        fn separate() {
+            let disks = [([0., 0.], 1.), ([3., 4.], 2.)];
+            let circle = enclosing_circle_of_two_disks2d(disks[0], disks[1]);
+            assert_touches(circle, &disks);
+            assert_eq!(circle.1, 4.);
+        }
+
+        #[test]
+        fn nested() {
+            assert_eq!(
+                enclosing_circle_of_two_disks2d(([0., 0.], 1.), ([0., 0.5], 2.)),
+                ([0., 0.5], 2.)
+            );
+            assert_eq!(
+                enclosing_circle_of_two_disks2d(([0., 0.5], 2.), ([0., 0.], 1.)),
+                ([0., 0.5], 2.)
+            );
+        }
+
+        #[test]
+        fn points() {
+            assert_eq!(
+                enclosing_circle_of_two_disks2d(([0., 0.], 0.), ([2., 0.], 0.)),
+                ([1., 0.], 1.)
+            );
+        }
+    }
+
+    mod three {
+        use super::*;
+
+        #[test]
+        fn points() {
+            let circle =
+                enclosing_circle_of_three_disks2d(([0., 0.], 0.), ([2., 0.], 0.), ([2., 2.], 0.))
+                    .unwrap();
+            assert!((circle.0[0] - 1.).abs() < 1e-12 && (circle.0[1] - 1.).abs() < 1e-12);
+            assert!((circle.1 - f64::sqrt(2.)).abs() < 1e-12);
+        }
+
+        #[test]
+        fn different_radii() {
+            let disks = [([0., 0.], 1.), ([5., 1.], 2.), ([2., 6.], 0.5)];
+            let circle = enclosing_circle_of_three_disks2d(disks[0], disks[1], disks[2]).unwrap();
+            assert_touches(circle, &disks);
+            assert_encloses(circle, &disks);
+        }
+
+        #[test]
+        fn collinear_centers() {
+            let disks = [([0., 0.], 1.), ([4., 0.], 1.), ([2., 0.], 2.)];
+            let circle = enclosing_circle_of_three_disks2d(disks[0], disks[1], disks[2]).unwrap();
+            assert_touches(circle, &disks);
+        }
+
+        #[test]
+        fn degenerate() {
+            assert_eq!(
+                enclosing_circle_of_three_disks2d(([0., 0.], 1.), ([2., 0.], 1.), ([4., 0.], 1.)),
+                None
+            );
+        }
+    }
+}
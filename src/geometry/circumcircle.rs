@@ -1,7 +1,11 @@
-use num::traits::real::Real;
+use num::{traits::real::Real, BigRational, Zero};
 
 use crate::{
-    geometry::{num::ConstTwo, point::PointLike},
+    error::CircleError,
+    geometry::{
+        num::{ConstTwo, Finite},
+        point::PointLike,
+    },
     predicates::orientation::{DefaultOrientationArea, OrientationArea},
 };
 
@@ -10,24 +14,27 @@ pub trait CircumCircle<CenterPoint, Radius> {
     fn circumcircle(&self) -> Option<(CenterPoint, Radius)>;
 }
 
-impl<P> CircumCircle<[f64; 2], f64> for [P; 3]
+impl<P, C> CircumCircle<[C; 2], C> for [P; 3]
 where
-    P: PointLike<f64, 2>,
+    P: PointLike<C, 2>,
+    C: Real + ConstTwo + Finite,
+    DefaultOrientationArea: OrientationArea<C>,
 {
-    fn circumcircle(&self) -> Option<([f64; 2], f64)> {
+    fn circumcircle(&self) -> Option<([C; 2], C)> {
         let &[a, b, c] = &self.each_ref().map(|p| p.coordinates());
-        Some(circumcircle2d::<f64, DefaultOrientationArea>(a, b, c))
+        try_circumcircle2d::<C, DefaultOrientationArea>(a, b, c).ok()
     }
 }
 
-impl<P> CircumCircle<[f64; 2], f64> for [P; 2]
+impl<P, C> CircumCircle<[C; 2], C> for [P; 2]
 where
-    P: PointLike<f64, 2>,
+    P: PointLike<C, 2>,
+    C: Real + ConstTwo,
 {
-    fn circumcircle(&self) -> Option<([f64; 2], f64)> {
+    fn circumcircle(&self) -> Option<([C; 2], C)> {
         let &[a, b] = &self.each_ref().map(|p| p.coordinates());
-        let center = [(a[0] + b[0]) / 2., (a[1] + b[1]) / 2.];
-        let radius = f64::hypot(a[0] - b[0], a[1] - b[1]) / 2.;
+        let center = [(a[0] + b[0]) / C::TWO, (a[1] + b[1]) / C::TWO];
+        let radius = (a[0] - b[0]).hypot(a[1] - b[1]) / C::TWO;
         Some((center, radius))
     }
 }
@@ -36,20 +43,108 @@ where
 ///
 /// # Panics
 ///
-/// This function panics if the given three points are collinear.
+/// This function panics if the given three points are collinear. See [`try_circumcircle2d`] for a non-panicking version.
 pub fn circumcircle2d<C, O>(a: [C; 2], b: [C; 2], c: [C; 2]) -> ([C; 2], C)
 where
     C: Real + ConstTwo,
     O: OrientationArea<C>,
 {
     let orientation = O::orientation(&a, &b, &c);
+    circumcircle2d_with_orientation(a, b, c, orientation)
+        .expect("cannot compute the circumcircle of collinear points")
+}
+
+/// Computed the center and radius of the circle given by the three points `a`, `b`, and `c`. Or, in other words, the circumcircle of the triangle `abc`.
+///
+/// Returns [`CircleError::NonFinite`] if any coordinate is NaN or infinite, and [`CircleError::Collinear`] if the given three points are collinear.
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::CircleError;
+/// use smallest_enclosing_circle::geometry::circumcircle::try_circumcircle2d;
+/// use smallest_enclosing_circle::predicates::orientation::DefaultOrientationArea;
+///
+/// let circle = try_circumcircle2d::<f64, DefaultOrientationArea>([-1., -1.], [1., -1.], [1., 1.]);
+/// assert_eq!(circle, Ok(([0., 0.], f64::sqrt(2.))));
+///
+/// let circle = try_circumcircle2d::<f64, DefaultOrientationArea>([0., 0.], [1., 0.], [2., 0.]);
+/// assert_eq!(circle, Err(CircleError::Collinear));
+/// ```
+pub fn try_circumcircle2d<C, O>(
+    a: [C; 2],
+    b: [C; 2],
+    c: [C; 2],
+) -> Result<([C; 2], C), CircleError>
+where
+    C: Real + ConstTwo + Finite,
+    O: OrientationArea<C>,
+{
+    if [a, b, c].iter().flatten().any(|x| !x.is_finite()) {
+        return Err(CircleError::NonFinite);
+    }
+    let orientation = O::orientation(&a, &b, &c);
+    circumcircle2d_with_orientation(a, b, c, orientation).ok_or(CircleError::Collinear)
+}
+
+/// Computes the exact center and squared radius of the circle given by the three points `a`, `b`, and `c` (i.e., the circumcircle of the triangle `abc`), using the same formula as [`circumcircle2d`] with arbitrary precision rationals.
+///
+/// Returns `None` if the given three points are collinear.
+pub fn exact_circumcircle2d(
+    a: [BigRational; 2],
+    b: [BigRational; 2],
+    c: [BigRational; 2],
+) -> Option<([BigRational; 2], BigRational)> {
+    let [acx, acy, bcx, bcy] = [
+        &a[0] - &c[0],
+        &a[1] - &c[1],
+        &b[0] - &c[0],
+        &b[1] - &c[1],
+    ];
+    let denominator = (&acx * &bcy - &acy * &bcx) * BigRational::from_integer(2.into());
+    if denominator.is_zero() {
+        return None;
+    }
+    let acxys = &acx * &acx + &acy * &acy;
+    let bcxys = &bcx * &bcx + &bcy * &bcy;
+    let offset = [
+        (&acxys * &bcy - &bcxys * &acy) / &denominator,
+        (&acx * &bcxys - &bcx * &acxys) / &denominator,
+    ];
+    let squared_radius = &offset[0] * &offset[0] + &offset[1] * &offset[1];
+    let [cx, cy] = c;
+    let [ox, oy] = offset;
+    Some(([cx + ox, cy + oy], squared_radius))
+}
 
+/// Computes the exact center and squared radius of the circle that has the segment between `a` and `b` as its diameter, with arbitrary precision rationals.
+pub fn exact_circumcircle2d_two(
+    a: [BigRational; 2],
+    b: [BigRational; 2],
+) -> ([BigRational; 2], BigRational) {
+    let two = BigRational::from_integer(2.into());
+    let [dx, dy] = [&a[0] - &b[0], &a[1] - &b[1]];
+    let squared_radius = (&dx * &dx + &dy * &dy) / (&two * &two);
+    let [ax, ay] = a;
+    let [bx, by] = b;
+    ([(ax + bx) / &two, (ay + by) / &two], squared_radius)
+}
+
+fn circumcircle2d_with_orientation<C>(
+    a: [C; 2],
+    b: [C; 2],
+    c: [C; 2],
+    orientation: C,
+) -> Option<([C; 2], C)>
+where
+    C: Real + ConstTwo,
+{
     let (b, c, denominator) = if orientation > C::zero() {
         (b, c, C::TWO * orientation)
     } else if orientation < C::zero() {
         (c, b, -C::TWO * orientation)
     } else {
-        panic!()
+        return None;
     };
 
     let [acx, acy, bcx, bcy, abx, aby] = [
@@ -74,7 +169,7 @@ where
         c[1] + (acx * bcxys - bcx * acxys) / denominator,
     ];
     let radius = (bcxys * acxys * abxys).sqrt() / denominator;
-    (center, radius)
+    Some((center, radius))
 }
 
 #[cfg(test)]
@@ -129,6 +224,120 @@ mod tests {
         }
     }
 
+    mod try_circumcircle2d {
+        use super::*;
+
+        #[test]
+        fn box_triangle() {
+            assert_eq!(
+                try_circumcircle2d::<f64, DefaultOrientationArea>(
+                    [-1.0, -1.0],
+                    [1.0, -1.0],
+                    [1.0, 1.0]
+                ),
+                Ok(([0., 0.], f64::sqrt(2.)))
+            )
+        }
+
+        #[test]
+        fn collinear() {
+            assert_eq!(
+                try_circumcircle2d::<f64, DefaultOrientationArea>([0.0, 0.0], [1.0, 1.0], [2.0, 2.0]),
+                Err(CircleError::Collinear)
+            )
+        }
+
+        #[test]
+        fn duplicate() {
+            assert_eq!(
+                try_circumcircle2d::<f64, DefaultOrientationArea>([0.0, 0.0], [1.0, 1.0], [0.0, 0.0]),
+                Err(CircleError::Collinear)
+            )
+        }
+
+        #[test]
+        fn nan() {
+            assert_eq!(
+                try_circumcircle2d::<f64, DefaultOrientationArea>([0.0, 0.0], [1.0, f64::NAN], [1.0, 1.0]),
+                Err(CircleError::NonFinite)
+            )
+        }
+
+        #[test]
+        fn infinite() {
+            assert_eq!(
+                try_circumcircle2d::<f64, DefaultOrientationArea>(
+                    [0.0, 0.0],
+                    [1.0, 0.0],
+                    [f64::INFINITY, 1.0]
+                ),
+                Err(CircleError::NonFinite)
+            )
+        }
+
+        #[test]
+        #[should_panic(expected = "collinear")]
+        fn panicking_version() {
+            circumcircle2d::<f64, DefaultOrientationArea>([0.0, 0.0], [1.0, 1.0], [2.0, 2.0]);
+        }
+    }
+
+    mod exact_circumcircle2d {
+        use super::*;
+
+        fn exact(p: [i64; 2]) -> [BigRational; 2] {
+            p.map(|x| BigRational::from_integer(x.into()))
+        }
+
+        #[test]
+        fn box_triangle() {
+            assert_eq!(
+                exact_circumcircle2d(exact([-1, -1]), exact([1, -1]), exact([1, 1])),
+                Some((exact([0, 0]), BigRational::from_integer(2.into())))
+            );
+            assert_eq!(
+                exact_circumcircle2d(exact([-1, -1]), exact([1, 1]), exact([1, -1])),
+                Some((exact([0, 0]), BigRational::from_integer(2.into())))
+            );
+        }
+
+        #[test]
+        fn collinear() {
+            assert_eq!(
+                exact_circumcircle2d(exact([0, 0]), exact([1, 1]), exact([2, 2])),
+                None
+            );
+        }
+
+        #[test]
+        fn two_points() {
+            assert_eq!(
+                exact_circumcircle2d_two(exact([-1, 0]), exact([1, 0])),
+                (exact([0, 0]), BigRational::from_integer(1.into()))
+            );
+        }
+    }
+
+    mod circumcircle_f32 {
+        use super::*;
+
+        #[test]
+        fn two_points() {
+            assert_eq!(
+                [[0.0f32, 0.0], [1.0, 0.0]].circumcircle(),
+                Some(([0.5f32, 0.], 0.5))
+            )
+        }
+
+        #[test]
+        fn three_points() {
+            assert_eq!(
+                [[-1.0f32, -1.0], [1.0, -1.0], [1.0, 1.0]].circumcircle(),
+                Some(([0.0f32, 0.0], f32::sqrt(2.0)))
+            )
+        }
+    }
+
     mod circumcircle_two_points {
         use super::*;
 
@@ -183,5 +392,10 @@ mod tests {
                 Some(([0.5, 0.5], f64::sqrt(2.0) / 2.0))
             )
         }
+
+        #[test]
+        fn collinear() {
+            assert_eq!([[0.0, 0.0], [1.0, 0.0], [2.0, 0.0]].circumcircle(), None)
+        }
     }
 }
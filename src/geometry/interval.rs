@@ -0,0 +1,158 @@
+use std::cmp::Ordering;
+
+use num::{BigRational, Signed, ToPrimitive};
+
+/// A closed interval `[lower, upper]` that is guaranteed to contain an exact value, which is not necessarily representable in the coordinate type itself.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Interval<C> {
+    pub lower: C,
+    pub upper: C,
+}
+
+impl<C> Interval<C>
+where
+    C: PartialOrd + Copy,
+{
+    /// Checks whether the given value lies within the interval (bounds included).
+    pub fn contains(&self, value: C) -> bool {
+        self.lower <= value && value <= self.upper
+    }
+}
+
+/// Floating-point coordinate types that allow to enclose an exact rational (or the square root of an exact rational) by the tightest possible [`Interval`].
+pub trait EncloseRational: Sized {
+    /// Returns the tightest interval of representable values that contains `value`.
+    fn enclose(value: &BigRational) -> Interval<Self>;
+
+    /// Returns the tightest interval of representable values that contains the square root of the non-negative `value`.
+    fn enclose_sqrt(value: &BigRational) -> Interval<Self>;
+}
+
+macro_rules! enclose_rational_impl {
+    ($t:ty, $to:ident) => {
+        impl EncloseRational for $t {
+            fn enclose(value: &BigRational) -> Interval<Self> {
+                let approximation = value.$to().unwrap_or(<$t>::NAN);
+                let approximation = if approximation.is_nan() {
+                    if value.is_negative() {
+                        <$t>::NEG_INFINITY
+                    } else {
+                        <$t>::INFINITY
+                    }
+                } else {
+                    approximation
+                };
+                let compare = |x: $t| compare(x, value, |x| BigRational::from_float(x));
+                let mut lower = approximation;
+                while compare(lower) == Ordering::Greater {
+                    lower = lower.next_down();
+                }
+                let mut upper = approximation;
+                while compare(upper) == Ordering::Less {
+                    upper = upper.next_up();
+                }
+                Interval { lower, upper }
+            }
+
+            fn enclose_sqrt(value: &BigRational) -> Interval<Self> {
+                let approximation = value.$to().map_or(<$t>::INFINITY, <$t>::sqrt);
+                let compare = |x: $t| {
+                    compare(x, value, |x| BigRational::from_float(x).map(|x| &x * &x))
+                };
+                let mut lower = approximation;
+                while lower > 0. && compare(lower) == Ordering::Greater {
+                    lower = lower.next_down();
+                }
+                let mut upper = approximation;
+                while compare(upper) == Ordering::Less {
+                    upper = upper.next_up();
+                }
+                Interval {
+                    lower: lower.max(0.),
+                    upper,
+                }
+            }
+        }
+    };
+}
+
+enclose_rational_impl!(f32, to_f32);
+enclose_rational_impl!(f64, to_f64);
+
+/// Compares the (transformed) float `x` with the exact `value`, where infinite values of `x` compare as expected.
+fn compare<T: num::Float>(
+    x: T,
+    value: &BigRational,
+    exact: impl Fn(T) -> Option<BigRational>,
+) -> Ordering {
+    match exact(x) {
+        Some(x) => x.cmp(value),
+        None if x > T::zero() => Ordering::Greater,
+        None => Ordering::Less,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rational(n: i64, d: i64) -> BigRational {
+        BigRational::new(n.into(), d.into())
+    }
+
+    #[test]
+    fn enclose_representable() {
+        assert_eq!(
+            f64::enclose(&rational(1, 2)),
+            Interval {
+                lower: 0.5,
+                upper: 0.5
+            }
+        );
+    }
+
+    #[test]
+    fn enclose_not_representable() {
+        let interval = f64::enclose(&rational(1, 3));
+        assert_eq!(interval.upper, interval.lower.next_up());
+        assert!(BigRational::from_float(interval.lower).unwrap() < rational(1, 3));
+        assert!(BigRational::from_float(interval.upper).unwrap() > rational(1, 3));
+    }
+
+    #[test]
+    fn enclose_negative_f32() {
+        let interval = f32::enclose(&rational(-1, 3));
+        assert_eq!(interval.upper, interval.lower.next_up());
+        assert!(interval.contains(-1. / 3.));
+    }
+
+    #[test]
+    fn enclose_sqrt_representable() {
+        assert_eq!(
+            f64::enclose_sqrt(&rational(9, 4)),
+            Interval {
+                lower: 1.5,
+                upper: 1.5
+            }
+        );
+        assert_eq!(
+            f64::enclose_sqrt(&rational(0, 1)),
+            Interval {
+                lower: 0.,
+                upper: 0.
+            }
+        );
+    }
+
+    #[test]
+    fn enclose_sqrt_not_representable() {
+        let interval = f64::enclose_sqrt(&rational(2, 1));
+        assert_eq!(interval.upper, interval.lower.next_up());
+        assert!(interval.contains(f64::sqrt(2.)));
+        let [lower, upper] = [interval.lower, interval.upper].map(|x| {
+            let x = BigRational::from_float(x).unwrap();
+            &x * &x
+        });
+        assert!(lower < rational(2, 1) && rational(2, 1) < upper);
+    }
+}
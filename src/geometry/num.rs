@@ -1,3 +1,6 @@
+use num::BigRational;
+
+/// Provides the constant `2` for coordinate types, which is needed to compute centers and radii.
 pub trait ConstTwo {
     const TWO: Self;
 }
@@ -12,3 +15,65 @@ macro_rules! two_impl {
 
 two_impl!(f32, 2.0);
 two_impl!(f64, 2.0);
+
+/// Allows to check whether a coordinate is an actual number, i.e., neither NaN nor infinite.
+pub trait Finite {
+    fn is_finite(&self) -> bool;
+}
+
+macro_rules! finite_impl {
+    ($t:ty) => {
+        impl Finite for $t {
+            fn is_finite(&self) -> bool {
+                <$t>::is_finite(*self)
+            }
+        }
+    };
+}
+
+finite_impl!(f32);
+finite_impl!(f64);
+
+macro_rules! integer_finite_impl {
+    ($t:ty) => {
+        impl Finite for $t {
+            fn is_finite(&self) -> bool {
+                true
+            }
+        }
+    };
+}
+
+integer_finite_impl!(i32);
+integer_finite_impl!(i64);
+
+/// Converts a coordinate exactly into an arbitrary precision rational. Returns `None` for NaN or infinite values.
+pub trait ToExactRational {
+    fn to_exact_rational(&self) -> Option<BigRational>;
+}
+
+macro_rules! float_to_exact_rational_impl {
+    ($t:ty) => {
+        impl ToExactRational for $t {
+            fn to_exact_rational(&self) -> Option<BigRational> {
+                BigRational::from_float(*self)
+            }
+        }
+    };
+}
+
+float_to_exact_rational_impl!(f32);
+float_to_exact_rational_impl!(f64);
+
+macro_rules! integer_to_exact_rational_impl {
+    ($t:ty) => {
+        impl ToExactRational for $t {
+            fn to_exact_rational(&self) -> Option<BigRational> {
+                Some(BigRational::from_integer((*self).into()))
+            }
+        }
+    };
+}
+
+integer_to_exact_rational_impl!(i32);
+integer_to_exact_rational_impl!(i64);
@@ -0,0 +1,144 @@
+use num::traits::{float::TotalOrder, real::Real};
+
+use crate::geometry::{apollonius::solve_quadratic, num::ConstTwo};
+
+/// Computes the center that minimizes `max(w_a |center - a|, w_b |center - b|)` for two weighted points `a` and `b` (given as point and positive weight), together with the minimal value (the *objective*).
+///
+/// The center lies on the segment between both points, where both weighted distances are equal, i.e., it divides the segment in the ratio `w_b : w_a`.
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::geometry::weighted::weighted_center2d_two;
+///
+/// assert_eq!(weighted_center2d_two(([0., 0.], 1.), ([4., 0.], 3.)), ([3., 0.], 3.));
+/// ```
+pub fn weighted_center2d_two<C>(a: ([C; 2], C), b: ([C; 2], C)) -> ([C; 2], C)
+where
+    C: Real,
+{
+    let (offset, objective) = weighted_offset2d_two(a, b);
+    ([a.0[0] + offset[0], a.0[1] + offset[1]], objective)
+}
+
+/// Same as [`weighted_center2d_two`], but returns the center as offset from `a`, which is not subject to the rounding errors of the absolute coordinates.
+pub(crate) fn weighted_offset2d_two<C>(a: ([C; 2], C), b: ([C; 2], C)) -> ([C; 2], C)
+where
+    C: Real,
+{
+    let (pa, wa) = a;
+    let (pb, wb) = b;
+    let t = wb / (wa + wb);
+    let offset = [t * (pb[0] - pa[0]), t * (pb[1] - pa[1])];
+    let distance = (pb[0] - pa[0]).hypot(pb[1] - pa[1]);
+    (offset, wa * t * distance)
+}
+
+/// Computes the center with equal weighted distances `w_i |center - p_i|` to the three weighted points `a`, `b`, and `c` (given as point and positive weight), such that this common value (the *objective*) is minimal.
+///
+/// With `μ` the squared objective, the conditions `|center - p_i|^2 = μ / w_i^2` are quadratic, but their pairwise differences are linear in the center and `μ`.
+/// The center is thus an affine function of `μ`, which is substituted into the condition of the first point, and the smallest non-negative root of the resulting quadratic is taken.
+/// `None` if no such center exists, or if the points are (numerically) collinear.
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::geometry::weighted::weighted_center2d_three;
+///
+/// let (center, objective) = weighted_center2d_three(([0f64, 0.], 1.), ([2., 0.], 1.), ([2., 2.], 1.)).unwrap();
+/// assert!((center[0] - 1.).abs() < 1e-12 && (center[1] - 1.).abs() < 1e-12);
+/// assert!((objective - f64::sqrt(2.)).abs() < 1e-12);
+/// ```
+pub fn weighted_center2d_three<C>(
+    a: ([C; 2], C),
+    b: ([C; 2], C),
+    c: ([C; 2], C),
+) -> Option<([C; 2], C)>
+where
+    C: Real + ConstTwo + TotalOrder,
+{
+    weighted_offset2d_three(a, b, c)
+        .map(|(offset, objective)| ([a.0[0] + offset[0], a.0[1] + offset[1]], objective))
+}
+
+/// Same as [`weighted_center2d_three`], but returns the center as offset from `a`, which is not subject to the rounding errors of the absolute coordinates.
+pub(crate) fn weighted_offset2d_three<C>(
+    a: ([C; 2], C),
+    b: ([C; 2], C),
+    c: ([C; 2], C),
+) -> Option<([C; 2], C)>
+where
+    C: Real + ConstTwo + TotalOrder,
+{
+    let (p0, w0) = a;
+    let u0 = (w0 * w0).recip();
+    // Unknowns relative to `a`: x = center - p0, with 2 e_i · x = |e_i|^2 - (u_i - u_0) μ.
+    let [e1, e2] = [b, c].map(|(pi, _)| [pi[0] - p0[0], pi[1] - p0[1]]);
+    let [k1, k2] = [e1, e2].map(|e| e[0] * e[0] + e[1] * e[1]);
+    let [d1, d2] = [b, c].map(|(_, wi)| (wi * wi).recip() - u0);
+    let determinant = C::TWO * (e1[0] * e2[1] - e1[1] * e2[0]);
+    if determinant.abs() <= C::TWO * k1.max(k2) * C::epsilon().sqrt() {
+        return None;
+    }
+    // Cramer's rule, separately for the constant and the linear part in μ.
+    let solve = |r1: C, r2: C| {
+        [
+            (r1 * e2[1] - r2 * e1[1]) / determinant,
+            (e1[0] * r2 - e2[0] * r1) / determinant,
+        ]
+    };
+    let x0 = solve(k1, k2);
+    let x1 = solve(-d1, -d2);
+
+    // Condition of `a` along the line: |x0 + μ x1|^2 = μ u_0.
+    let dot = |u: [C; 2], v: [C; 2]| u[0] * v[0] + u[1] * v[1];
+    let roots = solve_quadratic(dot(x1, x1), C::TWO * dot(x0, x1) - u0, dot(x0, x0));
+    let tolerance = C::epsilon().sqrt() * dot(x0, x0) / u0;
+    roots
+        .into_iter()
+        .flatten()
+        .filter(|mu| *mu >= -tolerance)
+        .min_by(|s, t| s.total_cmp(t))
+        .map(|mu| {
+            let mu = mu.max(C::zero());
+            ([x0[0] + mu * x1[0], x0[1] + mu * x1[1]], mu.sqrt())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_tight(solution: ([f64; 2], f64), points: &[([f64; 2], f64)]) {
+        for (p, w) in points {
+            let value = w * (p[0] - solution.0[0]).hypot(p[1] - solution.0[1]);
+            assert!((value - solution.1).abs() < 1e-9, "{solution:?} {p:?} {w}");
+        }
+    }
+
+    #[test]
+    fn two_equal_weights() {
+        assert_eq!(
+            weighted_center2d_two(([0., 0.], 2.), ([2., 0.], 2.)),
+            ([1., 0.], 2.)
+        );
+    }
+
+    #[test]
+    fn three_different_weights() {
+        // Points at distance 2 / w from (1, 1), in directions that surround the center.
+        let points = [([-1., 1.], 1.), ([1., 2.], 2.), ([3.4, -2.2], 0.5)];
+        let solution = weighted_center2d_three(points[0], points[1], points[2]).unwrap();
+        assert_tight(solution, &points);
+        assert!((solution.0[0] - 1.).abs() < 1e-9 && (solution.0[1] - 1.).abs() < 1e-9);
+        assert!((solution.1 - 2.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn three_collinear() {
+        assert_eq!(
+            weighted_center2d_three(([0., 0.], 1.), ([1., 0.], 1.), ([2., 0.], 1.)),
+            None
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{
+    algorithm::welzl,
+    circle::Circle2D,
+    geometry::point::PointLike,
+    predicates::{
+        in_circle::{DefaultInCircle, InCircle},
+        orientation::{DefaultOrientation, Orientation},
+    },
+    random::SplitMix64,
+};
+
+/// Keeps a set of points together with their smallest enclosing circle, and updates the circle exactly when points are inserted.
+///
+/// Inserting a point that is contained by the current circle takes `O(1)` time. Otherwise, the new point must lie on the boundary of the new circle, so only the problem with this point fixed as spanning point is solved (with the previous points in random order), which takes expected `O(n)` time.
+/// If the points are inserted in random order, the probability that the `i`-th point lies outside is at most `3/i`, so the expected time per insertion is `O(1)`.
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::{Circle2D, IncrementalEnclosingCircle};
+///
+/// let mut incremental = IncrementalEnclosingCircle::new();
+/// assert!(incremental.insert([0., 0.]));
+/// assert!(incremental.insert([2., 0.]));
+/// assert!(!incremental.insert([1., 0.5]));
+/// assert!(incremental.circle().equals(&Circle2D::new(&[[0., 0.], [2., 0.]])));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct IncrementalEnclosingCircle<Point> {
+    points: Vec<Point>,
+    circle: Circle2D<Point>,
+    seed: u64,
+}
+
+impl<Point> IncrementalEnclosingCircle<Point> {
+    /// Creates an empty structure.
+    pub fn new() -> Self {
+        IncrementalEnclosingCircle {
+            points: Vec::new(),
+            circle: Circle2D::None,
+            seed: 0,
+        }
+    }
+
+    /// The smallest enclosing circle of all points inserted so far.
+    pub fn circle(&self) -> &Circle2D<Point> {
+        &self.circle
+    }
+
+    /// All points inserted so far, in insertion order.
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    /// The number of points inserted so far.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Whether no points were inserted so far.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Inserts a point, and updates the circle if the point is not contained by it. Returns whether the circle changed.
+    pub fn insert<C>(&mut self, point: Point) -> bool
+    where
+        Point: PartialEq + PointLike<C, 2> + Copy,
+        C: PartialEq,
+        DefaultInCircle: InCircle<C>,
+        DefaultOrientation: Orientation<C>,
+    {
+        self.insert_with_predicate::<DefaultInCircle, C>(point)
+    }
+
+    /// See [`IncrementalEnclosingCircle::insert`]. Additionally, supports a custom [`InCircle`] predicate.
+    pub fn insert_with_predicate<InCirclePredicate, C>(&mut self, point: Point) -> bool
+    where
+        Point: PartialEq + PointLike<C, 2> + Copy,
+        InCirclePredicate: InCircle<C>,
+        C: PartialEq,
+        DefaultOrientation: Orientation<C>,
+    {
+        let contained = self
+            .circle
+            .contains_with_predicate::<Point, InCirclePredicate, C>(&point);
+        if !contained {
+            let mut points = self.points.clone();
+            self.seed += 1;
+            SplitMix64::new(self.seed).shuffle(&mut points);
+            self.circle = welzl(
+                points,
+                Vec::from([point]),
+                3,
+                |r| Circle2D::new(r),
+                |circle, element| {
+                    !circle.contains_with_predicate::<Point, InCirclePredicate, C>(element)
+                },
+            );
+        }
+        self.points.push(point);
+        !contained
+    }
+}
+
+impl<Point> Default for IncrementalEnclosingCircle<Point> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{algorithm::smallest_enclosing_circle, random::SplitMix64};
+
+    #[test]
+    fn empty() {
+        let incremental = IncrementalEnclosingCircle::<[f64; 2]>::default();
+        assert_eq!(incremental.circle(), &Circle2D::None);
+        assert!(incremental.is_empty());
+    }
+
+    #[test]
+    fn duplicates() {
+        let mut incremental = IncrementalEnclosingCircle::new();
+        assert!(incremental.insert([1., 1.]));
+        assert!(!incremental.insert([1., 1.]));
+        assert_eq!(incremental.circle(), &Circle2D::One { p: [1., 1.] });
+        assert_eq!(incremental.len(), 2);
+    }
+
+    #[test]
+    fn matches_batch() {
+        let mut rng = SplitMix64::new(17);
+        let points: Vec<[f64; 2]> = (0..500).map(|_| [rng.next_f64(), rng.next_f64()]).collect();
+        let mut incremental = IncrementalEnclosingCircle::new();
+        for (i, point) in points.iter().enumerate() {
+            incremental.insert(*point);
+            if i % 50 == 0 {
+                let expected = smallest_enclosing_circle(points[..=i].to_vec());
+                assert!(incremental.circle().equals(&expected));
+            }
+        }
+        assert!(incremental
+            .circle()
+            .equals(&smallest_enclosing_circle(points.clone())));
+        assert!(points.iter().all(|p| incremental.circle().contains(p)));
+        assert_eq!(incremental.points(), &points[..]);
+    }
+
+    #[test]
+    fn integer() {
+        let mut incremental = IncrementalEnclosingCircle::new();
+        for point in [[0i64, 0], [4, 0], [2, 1], [2, 3], [0, 4]] {
+            incremental.insert(point);
+        }
+        assert!(incremental
+            .circle()
+            .equals(&Circle2D::new(&[[0, 0], [4, 0], [0, 4]])));
+    }
+}
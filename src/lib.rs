@@ -3,10 +3,23 @@
 //! The original algorithm was formulated as a recursive program, which leads to a call stack overflow for larger problem sizes.
 //! Thus, the iterative implementation in this crate should be preferred.
 //! However, the recursive version is provided for demonstration purposes.
+//! For spatially sorted inputs, the move-to-front variant [`smallest_enclosing_circle_move_to_front`] is usually considerably faster.
+//! If an approximation suffices, [`smallest_enclosing_circle_approximate`] runs in deterministic linear time for a fixed relative error.
+//! If points are added one by one, [`IncrementalEnclosingCircle`] updates the exact circle only when necessary, and [`DynamicEnclosingCircle`] supports deletions as well.
+//! [`SlidingWindowEnclosingCircle`] keeps the exact circle of the last points of a stream, limited by count or by age.
+//! For unbounded streams that cannot be stored, [`StreamingEnclosingCircle`] maintains an approximate enclosing circle in constant memory.
 //!
-//! *Please note that the expected runtime only holds for randomized inputs (i.e., you may want to shuffle your input stream in advance).*
+//! *Please note that the expected runtime only holds for randomized inputs (i.e., you may want to shuffle your input stream in advance, or use [`smallest_enclosing_circle_randomized`], which does so with a seeded built-in generator).*
 //! 
 //! The main functionality of this crate is the [`smallest_enclosing_circle`] function.
+//! It accepts any point type implementing [`geometry::point::PointLike`], with `f64` or `f32` coordinates (the default predicates are exact for both).
+//! For bit-exact results, `i32` and `i64` coordinates are supported as well: the default predicates then use arbitrary precision integer arithmetic, and [`Circle2D::exact_center`] and [`Circle2D::exact_squared_radius`] provide the circle as exact rationals.
+//! For bounding spheres in 3D (or any other dimension), use [`smallest_enclosing_ball`].
+//! Minimum-area enclosing ellipses are computed by [`smallest_enclosing_ellipse`].
+//! If the input contains a few garbage points, [`smallest_enclosing_circle_with_outliers`] excludes up to `k` of them, and [`smallest_enclosing_circle_k_of_n`] finds the smallest circle that encloses at least `k` points (e.g., for hotspot detection).
+//! For the weighted 1-center problem (minimizing the maximum weighted distance), use [`smallest_enclosing_circle_weighted`].
+//! For roundness inspection, [`minimum_width_annulus`] computes the thinnest annulus that contains all points.
+//! To enclose disks instead of points (e.g., circular colliders), use [`smallest_enclosing_circle_of_circles`]. This also covers points with individual uncertainty radii, and [`smallest_enclosing_circle_of_circles_indexed`] reports which of them are tight.
 //!
 //! The implementation is based on the following work:
 //!
@@ -34,9 +47,46 @@
 //! However, if you don't specify your own predicates, then the default implementation is used, based on [`geometry_predicates`] crate, which is already a very reasonable choice.
 
 pub mod algorithm;
+pub mod annulus;
+pub mod ball;
 pub mod circle;
+pub mod disk;
+pub mod dynamic;
+pub mod ellipse;
+pub mod error;
 pub mod geometry;
+pub mod incremental;
 pub mod predicates;
+mod random;
+pub mod sliding_window;
+pub mod streaming;
+pub mod validation;
 
-pub use self::algorithm::{smallest_enclosing_circle, smallest_enclosing_circle_with_predicate};
+pub use self::algorithm::{
+    minimum_width_annulus, minimum_width_annulus_with_predicate, smallest_enclosing_ball,
+    smallest_enclosing_ball_randomized, smallest_enclosing_circle,
+    smallest_enclosing_circle_approximate,
+    smallest_enclosing_circle_indexed,
+    smallest_enclosing_circle_indexed_with_predicate, smallest_enclosing_circle_k_of_n,
+    smallest_enclosing_circle_k_of_n_with_predicate, smallest_enclosing_circle_move_to_front,
+    smallest_enclosing_circle_move_to_front_with_predicate,
+    smallest_enclosing_circle_of_circles, smallest_enclosing_circle_of_circles_indexed,
+    smallest_enclosing_circle_of_circles_indexed_with_predicate,
+    smallest_enclosing_circle_of_circles_with_predicate,
+    smallest_enclosing_circle_randomized, smallest_enclosing_circle_randomized_with_predicate,
+    smallest_enclosing_circle_weighted,
+    smallest_enclosing_circle_with_outliers, smallest_enclosing_circle_with_outliers_with_predicate,
+    smallest_enclosing_circle_with_predicate, smallest_enclosing_ellipse,
+    smallest_enclosing_ellipse_randomized, CoveringCircleResult, EnclosingCircleOfCirclesResult, EnclosingCircleResult,
+    OutlierCircleResult,
+};
+pub use self::annulus::Annulus;
+pub use self::ball::Ball;
 pub use self::circle::{Circle2D};
+pub use self::disk::{Disk, DiskCircle2D};
+pub use self::dynamic::DynamicEnclosingCircle;
+pub use self::ellipse::{Ellipse, Ellipse2D};
+pub use self::error::{CircleError, NonFinitePointsError};
+pub use self::incremental::IncrementalEnclosingCircle;
+pub use self::sliding_window::SlidingWindowEnclosingCircle;
+pub use self::streaming::StreamingEnclosingCircle;
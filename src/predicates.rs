@@ -1,2 +1,3 @@
+pub mod disk_in_circle;
 pub mod in_circle;
 pub mod orientation;
\ No newline at end of file
@@ -0,0 +1,100 @@
+use crate::{disk::DiskLike, geometry::point::PointLike, predicates::in_circle::InCircleState};
+
+/// A trait that allows to determine whether a given disk lies [`InCircleState::Inside`] of a circle, [`InCircleState::Outside`] of a circle, or touches the circle [`InCircleState::On`] its boundary from the inside. The circle is given by its `center` and `radius`.
+///
+/// This is the counterpart of [`crate::predicates::in_circle::InCircle`] for [`crate::algorithm::smallest_enclosing_circle_of_circles`].
+pub trait DiskInCircle<T> {
+    fn disk_in_circle(
+        center: &impl PointLike<T, 2>,
+        radius: T,
+        disk: &impl DiskLike<T>,
+    ) -> InCircleState;
+}
+
+/// An empty struct that implements the default [`DiskInCircle`] trait used in this library.
+pub struct DefaultDiskInCircle;
+
+macro_rules! float_disk_in_circle_impl {
+    ($t:ty) => {
+        impl DiskInCircle<$t> for DefaultDiskInCircle {
+            /// Default implementation of the [`DiskInCircle`] trait. In contrast to the point predicates, the circles spanned by disks cannot be represented exactly, so a disk is considered to touch the circle if `distance + disk radius` deviates from the circle's radius by less than a small multiple of the machine epsilon, relative to the magnitude of the distance and both radii (so the tolerance does not depend on the position of the circle).
+            fn disk_in_circle(
+                center: &impl PointLike<$t, 2>,
+                radius: $t,
+                disk: &impl DiskLike<$t>,
+            ) -> InCircleState {
+                let [c, d] = [center.coordinates(), disk.center()];
+                let distance = (d[0] - c[0]).hypot(d[1] - c[1]);
+                let excess = distance + disk.radius() - radius;
+                let magnitude = [distance, radius, disk.radius()]
+                    .into_iter()
+                    .fold(0., |max: $t, v| max.max(v.abs()));
+                let tolerance = 64. * <$t>::EPSILON * magnitude;
+                if excess > tolerance {
+                    InCircleState::Outside
+                } else if excess < -tolerance {
+                    InCircleState::Inside
+                } else {
+                    InCircleState::On
+                }
+            }
+        }
+    };
+}
+
+float_disk_in_circle_impl!(f32);
+float_disk_in_circle_impl!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inside() {
+        assert_eq!(
+            DefaultDiskInCircle::disk_in_circle(&[0., 0.], 3., &([1., 0.], 1.)),
+            InCircleState::Inside
+        )
+    }
+
+    #[test]
+    fn outside() {
+        assert_eq!(
+            DefaultDiskInCircle::disk_in_circle(&[0., 0.], 3., &([1., 0.], 2.5)),
+            InCircleState::Outside
+        )
+    }
+
+    #[test]
+    fn on() {
+        assert_eq!(
+            DefaultDiskInCircle::disk_in_circle(&[0., 0.], 3., &([1., 0.], 2.)),
+            InCircleState::On
+        );
+        assert_eq!(
+            DefaultDiskInCircle::disk_in_circle(&[0.1, 0.], 0.3, &([0.3, 0.], 0.1)),
+            InCircleState::On
+        );
+    }
+
+    #[test]
+    fn far_from_origin() {
+        // The disk sticks out by 1e-6, which is small compared to the coordinates, but not compared to the radii.
+        assert_eq!(
+            DefaultDiskInCircle::disk_in_circle(&[1e8, 0.], 1., &([1e8 + 0.5, 0.], 0.5 + 1e-6)),
+            InCircleState::Outside
+        );
+        assert_eq!(
+            DefaultDiskInCircle::disk_in_circle(&[1e8, 0.], 1., &([1e8 + 0.5, 0.], 0.5)),
+            InCircleState::On
+        );
+    }
+
+    #[test]
+    fn f32() {
+        assert_eq!(
+            DefaultDiskInCircle::disk_in_circle(&[0f32, 0.], 3., &([1., 0.], 2.)),
+            InCircleState::On
+        )
+    }
+}
@@ -1,4 +1,5 @@
 use geometry_predicates::incircle;
+use num::{BigInt, BigRational, Signed};
 
 use crate::geometry::point::PointLike;
 
@@ -11,13 +12,56 @@ pub enum InCircleState {
 }
 
 ///A trait that allows to determine whether a given probe point lies [`InCircleState::Inside`]of a circle, [`InCircleState::Outside`] of a circle, or exactly [`InCircleState::On`] a circle given by points `a`, `b`, and `c`.
-pub trait InCircle<T> {
+///
+/// It can be implemented for the coordinate types `f32`, `f64`, `i32`, and `i64`, which provide the exact default of [`InCircle::in_diametral_circle`].
+pub trait InCircle<T>: sealed::DiametralDefault<T> {
     fn in_circle(
         a: &impl PointLike<T, 2>,
         b: &impl PointLike<T, 2>,
         c: &impl PointLike<T, 2>,
         probe: &impl PointLike<T, 2>,
     ) -> InCircleState;
+
+    /// Determines whether the given probe point lies [`InCircleState::Inside`], [`InCircleState::Outside`], or exactly [`InCircleState::On`] the circle that has the segment between the two distinct points `a` and `b` as its diameter.
+    ///
+    /// Defaults to the exact sign of the dot product `(probe - a) · (probe - b)`. Override it if your predicate has to make different decisions.
+    fn in_diametral_circle(
+        a: &impl PointLike<T, 2>,
+        b: &impl PointLike<T, 2>,
+        probe: &impl PointLike<T, 2>,
+    ) -> InCircleState {
+        Self::exact_in_diametral_circle(a, b, probe)
+    }
+}
+
+mod sealed {
+    use super::InCircleState;
+    use crate::geometry::point::PointLike;
+
+    /// The coordinate types with an exact diametral circle test, i.e., `f32`, `f64`, `i32`, and `i64`.
+    pub trait DiametralCoordinate: Sized {
+        /// Determines the location of `probe` with respect to the circle with diameter `(a, b)` from the exact sign of the dot product `(probe - a) · (probe - b)`.
+        fn in_diametral_circle(a: [Self; 2], b: [Self; 2], probe: [Self; 2]) -> InCircleState;
+    }
+
+    /// Provides the default of [`super::InCircle::in_diametral_circle`] for these coordinate types.
+    pub trait DiametralDefault<T> {
+        fn exact_in_diametral_circle(
+            a: &impl PointLike<T, 2>,
+            b: &impl PointLike<T, 2>,
+            probe: &impl PointLike<T, 2>,
+        ) -> InCircleState;
+    }
+
+    impl<P: ?Sized, T: DiametralCoordinate> DiametralDefault<T> for P {
+        fn exact_in_diametral_circle(
+            a: &impl PointLike<T, 2>,
+            b: &impl PointLike<T, 2>,
+            probe: &impl PointLike<T, 2>,
+        ) -> InCircleState {
+            T::in_diametral_circle(a.coordinates(), b.coordinates(), probe.coordinates())
+        }
+    }
 }
 
 /// An empty struct that implements the default [`InCircle`] trait used in this library.
@@ -47,6 +91,197 @@ impl InCircle<f64> for DefaultInCircle {
     }
 }
 
+impl InCircle<f32> for DefaultInCircle {
+    /// Default implementation of the [`InCircle`] trait for `f32` coordinates. Every `f32` value is exactly representable as `f64`, so the robust `f64` predicate of the [`geometry_predicates`] crate yields exact results here as well.
+    fn in_circle(
+        a: &impl PointLike<f32, 2>,
+        b: &impl PointLike<f32, 2>,
+        c: &impl PointLike<f32, 2>,
+        probe: &impl PointLike<f32, 2>,
+    ) -> InCircleState {
+        <DefaultInCircle as InCircle<f64>>::in_circle(
+            &widen(a.coordinates()),
+            &widen(b.coordinates()),
+            &widen(c.coordinates()),
+            &widen(probe.coordinates()),
+        )
+    }
+}
+
+macro_rules! integer_in_circle_impl {
+    ($t:ty) => {
+        impl InCircle<$t> for DefaultInCircle {
+            /// Exact implementation of the [`InCircle`] trait for integer coordinates, evaluates the determinant with arbitrary precision integers.
+            fn in_circle(
+                a: &impl PointLike<$t, 2>,
+                b: &impl PointLike<$t, 2>,
+                c: &impl PointLike<$t, 2>,
+                probe: &impl PointLike<$t, 2>,
+            ) -> InCircleState {
+                let [a, b, c, d] = [
+                    a.coordinates(),
+                    b.coordinates(),
+                    c.coordinates(),
+                    probe.coordinates(),
+                ]
+                .map(|p| p.map(BigInt::from));
+                let [adx, ady, bdx, bdy, cdx, cdy] = [
+                    &a[0] - &d[0],
+                    &a[1] - &d[1],
+                    &b[0] - &d[0],
+                    &b[1] - &d[1],
+                    &c[0] - &d[0],
+                    &c[1] - &d[1],
+                ];
+                let alift = &adx * &adx + &ady * &ady;
+                let blift = &bdx * &bdx + &bdy * &bdy;
+                let clift = &cdx * &cdx + &cdy * &cdy;
+                let o = alift * (&bdx * &cdy - &cdx * &bdy)
+                    + blift * (&cdx * &ady - &adx * &cdy)
+                    + clift * (&adx * &bdy - &bdx * &ady);
+                in_circle_state(&o)
+            }
+        }
+
+        impl sealed::DiametralCoordinate for $t {
+            /// Exact diametral circle test for integer coordinates, evaluates the dot product with arbitrary precision integers.
+            fn in_diametral_circle(a: [$t; 2], b: [$t; 2], probe: [$t; 2]) -> InCircleState {
+                let [a, b, p] = [a, b, probe].map(|p| p.map(BigInt::from));
+                let dot = (&p[0] - &a[0]) * (&p[0] - &b[0]) + (&p[1] - &a[1]) * (&p[1] - &b[1]);
+                in_circle_state(&-dot)
+            }
+        }
+    };
+}
+
+integer_in_circle_impl!(i32);
+integer_in_circle_impl!(i64);
+
+impl sealed::DiametralCoordinate for f64 {
+    /// Exact diametral circle test, a floating-point evaluation is used whenever its error bound allows, otherwise the dot product is evaluated exactly with floating-point expansions.
+    fn in_diametral_circle(a: [f64; 2], b: [f64; 2], probe: [f64; 2]) -> InCircleState {
+        let dot = diametral_dot(a, b, probe);
+        if dot < 0. {
+            InCircleState::Inside
+        } else if dot > 0. {
+            InCircleState::Outside
+        } else {
+            InCircleState::On
+        }
+    }
+}
+
+impl sealed::DiametralCoordinate for f32 {
+    /// Exact diametral circle test for `f32` coordinates, evaluated on the widened `f64` coordinates.
+    fn in_diametral_circle(a: [f32; 2], b: [f32; 2], probe: [f32; 2]) -> InCircleState {
+        <f64 as sealed::DiametralCoordinate>::in_diametral_circle(widen(a), widen(b), widen(probe))
+    }
+}
+
+/// Maps a determinant to the [`InCircleState`], positive values are inside.
+fn in_circle_state<T: Signed>(o: &T) -> InCircleState {
+    if o.is_positive() {
+        InCircleState::Inside
+    } else if o.is_negative() {
+        InCircleState::Outside
+    } else {
+        InCircleState::On
+    }
+}
+
+/// Computes a value with the same sign as the dot product `(p - a) · (p - b)`.
+fn diametral_dot(a: [f64; 2], b: [f64; 2], p: [f64; 2]) -> f64 {
+    let [x, y] = [0, 1].map(|i| (p[i] - a[i]) * (p[i] - b[i]));
+    let dot = x + y;
+    // Each product carries a relative error of at most 3u + O(u^2), the sum adds another u, 8u = 4ε is thus a safe bound (u = 2^-53, ε = f64::EPSILON = 2u).
+    // This does not hold for subnormal products, which carry an absolute error instead.
+    let error_bound = 4. * f64::EPSILON * (x.abs() + y.abs());
+    if dot.abs() > error_bound
+        && error_bound >= f64::MIN_POSITIVE
+        && !x.is_subnormal()
+        && !y.is_subnormal()
+        && error_bound.is_finite()
+    {
+        return dot;
+    }
+    // Non-finite inputs have no exact dot product, the NaN or infinite result is passed on.
+    if a.iter().chain(&b).chain(&p).any(|c| !c.is_finite()) {
+        return dot;
+    }
+
+    // Below this magnitude, the error of a product is not necessarily representable (see `two_product`).
+    let underflow_bound = 2. * f64::MIN_POSITIVE / f64::EPSILON;
+    let mut expansion = Vec::with_capacity(16);
+    for i in 0..2 {
+        let (pa, pa_tail) = two_diff(p[i], a[i]);
+        let (pb, pb_tail) = two_diff(p[i], b[i]);
+        for (u, v) in [(pa, pb), (pa, pb_tail), (pa_tail, pb), (pa_tail, pb_tail)] {
+            let (product, product_tail) = two_product(u, v);
+            // Overflowing differences or products have no representable error either.
+            if (u != 0. && v != 0. && product.abs() < underflow_bound)
+                || !product.is_finite()
+                || !product_tail.is_finite()
+            {
+                return exact_diametral_dot(a, b, p);
+            }
+            grow_expansion(&mut expansion, product_tail);
+            grow_expansion(&mut expansion, product);
+        }
+    }
+    expansion
+        .into_iter()
+        .rev()
+        .find(|component| *component != 0.)
+        .unwrap_or(0.)
+}
+
+/// Computes the sign of the dot product `(p - a) · (p - b)` with arbitrary precision rationals, as `-1`, `0`, or `1`.
+fn exact_diametral_dot(a: [f64; 2], b: [f64; 2], p: [f64; 2]) -> f64 {
+    let [a, b, p] = [a, b, p].map(|q| q.map(|c| BigRational::from_float(c).unwrap_or_default()));
+    let dot = (&p[0] - &a[0]) * (&p[0] - &b[0]) + (&p[1] - &a[1]) * (&p[1] - &b[1]);
+    if dot.is_positive() {
+        1.
+    } else if dot.is_negative() {
+        -1.
+    } else {
+        0.
+    }
+}
+
+/// Computes `a + b` exactly as the (non-overlapping) sum of the rounded result and its error.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    (x, (a - a_virtual) + (b - b_virtual))
+}
+
+/// Computes `a - b` exactly as the (non-overlapping) sum of the rounded result and its error.
+fn two_diff(a: f64, b: f64) -> (f64, f64) {
+    two_sum(a, -b)
+}
+
+/// Computes `a * b` exactly as the (non-overlapping) sum of the rounded result and its error, as long as `|a * b|` is at least `2^-969`, i.e., the error does not underflow.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    (x, a.mul_add(b, -x))
+}
+
+/// Adds `b` to the expansion, keeping its components non-overlapping and sorted by increasing magnitude (Shewchuk's Grow-Expansion).
+fn grow_expansion(expansion: &mut Vec<f64>, b: f64) {
+    let mut q = b;
+    for component in expansion.iter_mut() {
+        let (sum, error) = two_sum(q, *component);
+        *component = error;
+        q = sum;
+    }
+    expansion.push(q);
+}
+
+pub(crate) fn widen(point: [f32; 2]) -> [f64; 2] {
+    point.map(f64::from)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,4 +309,260 @@ mod tests {
             InCircleState::On
         )
     }
+
+    mod diametral {
+        use super::*;
+
+        #[test]
+        fn inside() {
+            assert_eq!(
+                DefaultInCircle::in_diametral_circle(&[0.0, 0.0], &[1.0, 0.0], &[0.5, 0.4]),
+                InCircleState::Inside
+            )
+        }
+
+        #[test]
+        fn outside() {
+            assert_eq!(
+                DefaultInCircle::in_diametral_circle(&[0.0, 0.0], &[1.0, 0.0], &[0.5, 0.6]),
+                InCircleState::Outside
+            )
+        }
+
+        #[test]
+        fn on() {
+            assert_eq!(
+                DefaultInCircle::in_diametral_circle(&[0.0, 0.0], &[1.0, 0.0], &[0.5, -0.5]),
+                InCircleState::On
+            )
+        }
+
+        #[test]
+        fn custom_predicate() {
+            // Predicates that only implement `in_circle` get the diametral test for free.
+            struct Custom;
+            impl InCircle<f64> for Custom {
+                fn in_circle(
+                    a: &impl PointLike<f64, 2>,
+                    b: &impl PointLike<f64, 2>,
+                    c: &impl PointLike<f64, 2>,
+                    probe: &impl PointLike<f64, 2>,
+                ) -> InCircleState {
+                    DefaultInCircle::in_circle(a, b, c, probe)
+                }
+            }
+            let circle = crate::circle::Circle2D::new(&[[0.0, 0.0], [1.0, 0.0]]);
+            assert!(circle.contains_with_predicate::<_, Custom, f64>(&[0.5, 0.4]));
+            assert!(!circle.contains_with_predicate::<_, Custom, f64>(&[0.5, 0.6]));
+        }
+
+        #[test]
+        fn overridden_predicate() {
+            // Predicates that override `in_diametral_circle` make the decisions for two-point circles as well.
+            struct Everywhere;
+            impl InCircle<f64> for Everywhere {
+                fn in_circle(
+                    _: &impl PointLike<f64, 2>,
+                    _: &impl PointLike<f64, 2>,
+                    _: &impl PointLike<f64, 2>,
+                    _: &impl PointLike<f64, 2>,
+                ) -> InCircleState {
+                    InCircleState::Inside
+                }
+
+                fn in_diametral_circle(
+                    _: &impl PointLike<f64, 2>,
+                    _: &impl PointLike<f64, 2>,
+                    _: &impl PointLike<f64, 2>,
+                ) -> InCircleState {
+                    InCircleState::Inside
+                }
+            }
+            let circle = crate::circle::Circle2D::new(&[[0.0, 0.0], [1.0, 0.0]]);
+            assert!(circle.contains_with_predicate::<_, Everywhere, f64>(&[5.0, 5.0]));
+            assert!(!circle.is_on_circle_with_predicate::<Everywhere, f64>(&[0.5, 0.5]));
+        }
+    }
+
+    mod diametral_exact {
+        use super::*;
+
+        #[test]
+        fn near_boundary() {
+            // The probe lies on the circle with diameter (a, b), nudging it by one ulp must flip the result.
+            let [a, b] = [[-1e8, 0.], [1e8, 0.]];
+            let probe: [f64; 2] = [0.6e8, 0.8e8];
+            assert_eq!(
+                DefaultInCircle::in_diametral_circle(&a, &b, &probe),
+                InCircleState::On
+            );
+            assert_eq!(
+                DefaultInCircle::in_diametral_circle(&a, &b, &[probe[0], probe[1].next_up()]),
+                InCircleState::Outside
+            );
+            assert_eq!(
+                DefaultInCircle::in_diametral_circle(&a, &b, &[probe[0], probe[1].next_down()]),
+                InCircleState::Inside
+            );
+        }
+
+        #[test]
+        fn inexact_differences() {
+            // Neither difference is representable, the circle with diameter (a, b) passes through `probe` only in exact arithmetic.
+            let a = [0.1, 0.];
+            let b = [-0.1, 0.];
+            let probe = [0., 0.1];
+            assert_eq!(
+                DefaultInCircle::in_diametral_circle(&a, &b, &probe),
+                InCircleState::On
+            );
+            let a = [0.1, 0.3];
+            let b = [0.7, 0.3];
+            for probe in [[0.4, 0.6], [0.4, 0.], [0.1, 0.3], [0.7, 0.3]] {
+                let rational = |p: [f64; 2]| p.map(|c| num::BigRational::from_float(c).unwrap());
+                let [a, b, p] = [rational(a), rational(b), rational(probe)];
+                let dot = (&p[0] - &a[0]) * (&p[0] - &b[0]) + (&p[1] - &a[1]) * (&p[1] - &b[1]);
+                assert_eq!(
+                    DefaultInCircle::in_diametral_circle(&[0.1, 0.3], &[0.7, 0.3], &probe),
+                    in_circle_state(&-dot)
+                );
+            }
+        }
+
+        #[test]
+        fn subnormal() {
+            // All products underflow to zero, only an exact evaluation sees the difference of one ulp.
+            let d = f64::MIN_POSITIVE / 2f64.powi(38);
+            let [a, b] = [[-d, 0.], [d, 0.]];
+            assert_eq!(
+                DefaultInCircle::in_diametral_circle(&a, &b, &[0., d]),
+                InCircleState::On
+            );
+            assert_eq!(
+                DefaultInCircle::in_diametral_circle(&a, &b, &[0., d.next_up()]),
+                InCircleState::Outside
+            );
+            assert_eq!(
+                DefaultInCircle::in_diametral_circle(&a, &b, &[0., d.next_down()]),
+                InCircleState::Inside
+            );
+        }
+
+        #[test]
+        fn huge_coordinates() {
+            // The products overflow, only an exact evaluation determines the sign.
+            let [a, b] = [[-1e200, 0.], [1e200, 0.]];
+            assert_eq!(
+                DefaultInCircle::in_diametral_circle(&a, &b, &[0., 1e200]),
+                InCircleState::On
+            );
+            assert_eq!(
+                DefaultInCircle::in_diametral_circle(&a, &b, &[0., 2e200]),
+                InCircleState::Outside
+            );
+            assert_eq!(
+                DefaultInCircle::in_diametral_circle(&a, &b, &[0., 0.5e200]),
+                InCircleState::Inside
+            );
+            let circle = crate::circle::Circle2D::new(&[a, b]);
+            assert!(!circle.contains(&[0., 2e200]));
+            let circle = crate::smallest_enclosing_circle([a, b, [0., 3e200]]);
+            assert!(circle.contains(&[0., 3e200]));
+        }
+
+        #[test]
+        fn expansion_sign() {
+            let mut expansion = Vec::new();
+            for b in [1e30, 1., -1e30] {
+                grow_expansion(&mut expansion, b);
+            }
+            assert_eq!(expansion.iter().rev().find(|c| **c != 0.), Some(&1.));
+        }
+    }
+
+    mod integer {
+        use super::*;
+
+        #[test]
+        fn inside() {
+            assert_eq!(
+                DefaultInCircle::in_circle(&[0i32, 0], &[2, 0], &[2, 2], &[1, 1]),
+                InCircleState::Inside
+            )
+        }
+
+        #[test]
+        fn outside() {
+            assert_eq!(
+                DefaultInCircle::in_circle(&[0i64, 0], &[2, 0], &[2, 2], &[3, 3]),
+                InCircleState::Outside
+            )
+        }
+
+        #[test]
+        fn on() {
+            assert_eq!(
+                DefaultInCircle::in_circle(&[0i32, 0], &[2, 0], &[2, 2], &[0, 2]),
+                InCircleState::On
+            )
+        }
+
+        #[test]
+        fn large_coordinates() {
+            let m = i64::MAX;
+            assert_eq!(
+                DefaultInCircle::in_circle(&[-m, -m], &[m, -m], &[m, m], &[-m, m]),
+                InCircleState::On
+            );
+            assert_eq!(
+                DefaultInCircle::in_circle(&[-m, -m], &[m, -m], &[m, m], &[-m, m - 1]),
+                InCircleState::Inside
+            );
+        }
+
+        #[test]
+        fn diametral() {
+            let m = i64::MAX;
+            assert_eq!(
+                DefaultInCircle::in_diametral_circle(&[-m, 0], &[m, 0], &[0, m]),
+                InCircleState::On
+            );
+            assert_eq!(
+                DefaultInCircle::in_diametral_circle(&[-m, 0], &[m, 0], &[1, m]),
+                InCircleState::Outside
+            );
+            assert_eq!(
+                DefaultInCircle::in_diametral_circle(&[-m, 0], &[m, 0], &[1, m - 1]),
+                InCircleState::Inside
+            );
+        }
+    }
+
+    mod f32 {
+        use super::*;
+
+        #[test]
+        fn inside() {
+            assert_eq!(
+                DefaultInCircle::in_circle(&[0.0f32, 0.0], &[1.0, 0.0], &[1.0, 1.0], &[0.5, 0.5]),
+                InCircleState::Inside
+            )
+        }
+
+        #[test]
+        fn outside() {
+            assert_eq!(
+                DefaultInCircle::in_circle(&[0.0f32, 0.0], &[1.0, 0.0], &[1.0, 1.0], &[1.5, 1.5]),
+                InCircleState::Outside
+            )
+        }
+
+        #[test]
+        fn on() {
+            assert_eq!(
+                DefaultInCircle::in_circle(&[0.0f32, 0.0], &[1.0, 0.0], &[1.0, 1.0], &[0.0, 1.0]),
+                InCircleState::On
+            )
+        }
+    }
 }
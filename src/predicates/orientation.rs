@@ -1,6 +1,7 @@
 use geometry_predicates::orient2d;
+use num::{BigInt, Signed};
 
-use crate::geometry::point::PointLike;
+use crate::{geometry::point::PointLike, predicates::in_circle::widen};
 
 /// Defines the determined state as a result of the [`Orientation::orientation`] operation, i.e., whether the three given points are in [`OrientationState::CounterClockwise`], [`OrientationState::Clockwise`], or [`OrientationState::Collinear`] order (mathematical, upward y-axis).
 #[derive(Debug, PartialEq, Copy, Clone)]
@@ -40,6 +41,48 @@ impl Orientation<f64> for DefaultOrientation {
     }
 }
 
+impl Orientation<f32> for DefaultOrientation {
+    /// Default implementation of the [`Orientation`] trait for `f32` coordinates, evaluates the robust `f64` predicate of the [`geometry_predicates`] crate (exact, since every `f32` value is exactly representable as `f64`).
+    fn orientation(
+        a: &impl PointLike<f32, 2>,
+        b: &impl PointLike<f32, 2>,
+        c: &impl PointLike<f32, 2>,
+    ) -> OrientationState {
+        <DefaultOrientation as Orientation<f64>>::orientation(
+            &widen(a.coordinates()),
+            &widen(b.coordinates()),
+            &widen(c.coordinates()),
+        )
+    }
+}
+
+macro_rules! integer_orientation_impl {
+    ($t:ty) => {
+        impl Orientation<$t> for DefaultOrientation {
+            /// Exact implementation of the [`Orientation`] trait for integer coordinates, evaluates the determinant with arbitrary precision integers.
+            fn orientation(
+                a: &impl PointLike<$t, 2>,
+                b: &impl PointLike<$t, 2>,
+                c: &impl PointLike<$t, 2>,
+            ) -> OrientationState {
+                let [a, b, c] = [a.coordinates(), b.coordinates(), c.coordinates()]
+                    .map(|p| p.map(BigInt::from));
+                let o = (&a[0] - &c[0]) * (&b[1] - &c[1]) - (&a[1] - &c[1]) * (&b[0] - &c[0]);
+                if o.is_positive() {
+                    OrientationState::CounterClockwise
+                } else if o.is_negative() {
+                    OrientationState::Clockwise
+                } else {
+                    OrientationState::Collinear
+                }
+            }
+        }
+    };
+}
+
+integer_orientation_impl!(i32);
+integer_orientation_impl!(i64);
+
 /// *Almost* identical to the [`Orientation`] trait, but returns the signed area of the spanned parallelogram of the given three points. The sign also indicates the same information as [`Orientation::orientation`] (positive if counterclockwise, negative if clockwise, otherwise collinear for mathematical, upward y-axis). This is identical to the [`geometry_predicates`] definition and necessary for the computation of circumcircles.
 pub trait OrientationArea<T> {
     fn orientation(
@@ -63,6 +106,21 @@ impl OrientationArea<f64> for DefaultOrientationArea {
     }
 }
 
+impl OrientationArea<f32> for DefaultOrientationArea {
+    /// Default implementation of the [`OrientationArea`] trait for `f32` coordinates, evaluates the `f64` predicate of the [`geometry_predicates`] crate and rounds the result to `f32`.
+    fn orientation(
+        a: &impl PointLike<f32, 2>,
+        b: &impl PointLike<f32, 2>,
+        c: &impl PointLike<f32, 2>,
+    ) -> f32 {
+        orient2d(
+            widen(a.coordinates()),
+            widen(b.coordinates()),
+            widen(c.coordinates()),
+        ) as f32
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -91,6 +149,39 @@ mod tests {
                 OrientationState::Collinear
             )
         }
+
+        #[test]
+        fn integer() {
+            let m = i64::MAX;
+            assert_eq!(
+                DefaultOrientation::orientation(&[-m, -m], &[m, -m], &[m, m]),
+                OrientationState::CounterClockwise
+            );
+            assert_eq!(
+                DefaultOrientation::orientation(&[-m, -m], &[m, m], &[m - 1, m - 1]),
+                OrientationState::Collinear
+            );
+            assert_eq!(
+                DefaultOrientation::orientation(&[0i32, 0], &[1, 0], &[1, -1]),
+                OrientationState::Clockwise
+            );
+        }
+
+        #[test]
+        fn f32() {
+            assert_eq!(
+                DefaultOrientation::orientation(&[0.0f32, 0.0], &[1.0, 0.0], &[1.0, 1.0]),
+                OrientationState::CounterClockwise
+            );
+            assert_eq!(
+                DefaultOrientation::orientation(&[0.1f32, 0.1], &[0.2, 0.2], &[0.3, 0.3]),
+                <DefaultOrientation as Orientation<f64>>::orientation(
+                    &[0.1f32 as f64, 0.1f32 as f64],
+                    &[0.2f32 as f64, 0.2f32 as f64],
+                    &[0.3f32 as f64, 0.3f32 as f64]
+                )
+            );
+        }
     }
 
     mod orientation_area {
@@ -118,5 +209,13 @@ mod tests {
                 0.0
             )
         }
+
+        #[test]
+        fn f32() {
+            assert_eq!(
+                DefaultOrientationArea::orientation(&[0.0f32, 0.0], &[1.0, 0.0], &[1.0, -1.0]),
+                -1.0f32
+            )
+        }
     }
 }
@@ -0,0 +1,69 @@
+/// A small, dependency-free pseudo random number generator (SplitMix64).
+///
+/// It is only used to shuffle input points, so statistical quality matters far less than reproducibility: the same seed yields the same sequence on every platform.
+pub(crate) struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub(crate) fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `0..bound` (`bound` must be positive), using Lemire's multiply-shift reduction.
+    pub(crate) fn next_below(&mut self, bound: u64) -> u64 {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+
+    /// Returns a number in `[0, 1)`, uniformly distributed on a grid of spacing `2^-53`.
+    #[cfg(test)]
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Shuffles the given slice in place (Fisher-Yates).
+    pub(crate) fn shuffle<T>(&mut self, elements: &mut [T]) {
+        for i in (1..elements.len()).rev() {
+            let j = self.next_below(i as u64 + 1) as usize;
+            elements.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reproducible() {
+        let mut a = SplitMix64::new(42);
+        let mut b = SplitMix64::new(42);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn reference_sequence() {
+        let mut rng = SplitMix64::new(0);
+        assert_eq!(rng.next_u64(), 0xE220_A839_7B1D_CDAF);
+        assert_eq!(rng.next_u64(), 0x6E78_9E6A_A1B9_65F4);
+    }
+
+    #[test]
+    fn shuffle_is_permutation() {
+        let mut elements: Vec<usize> = (0..100).collect();
+        SplitMix64::new(7).shuffle(&mut elements);
+        assert_ne!(elements, (0..100).collect::<Vec<_>>());
+        elements.sort();
+        assert_eq!(elements, (0..100).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,281 @@
+use std::{collections::VecDeque, ops::Sub};
+
+use crate::{
+    circle::Circle2D,
+    dynamic::DynamicEnclosingCircle,
+    geometry::point::PointLike,
+    predicates::{
+        in_circle::{DefaultInCircle, InCircle},
+        orientation::{DefaultOrientation, Orientation},
+    },
+};
+
+/// Maintains the smallest enclosing circle of the most recent points of a stream, limited either by count (the last `n` points) or by age (the points of the last `t` time units).
+///
+/// The window is backed by a [`DynamicEnclosingCircle`]: pushing a point inside the current circle, and expiring a point that does not span the current circle, take `O(1)` time.
+/// If a point leaves the window that spans the circle, the circle is recomputed from the `W` points in the window, which takes expected `O(W)` time.
+///
+/// As points leave the window in the order of their arrival, the oldest point often spans the circle, e.g., for points along a monotone trajectory.
+/// Then, every push takes expected `O(W)` time, just as recomputing the circle of the window from scratch.
+///
+/// # Examples
+///
+/// Window of the last three points:
+///
+/// ```
+/// use smallest_enclosing_circle::{Circle2D, SlidingWindowEnclosingCircle};
+///
+/// let mut window = SlidingWindowEnclosingCircle::new(3);
+/// for point in [[0., 0.], [10., 0.], [1., 0.], [2., 0.]] {
+///     window.push(point);
+/// }
+/// assert!(window.circle().equals(&Circle2D::new(&[[10., 0.], [1., 0.]])));
+/// ```
+///
+/// Window of the last five seconds:
+///
+/// ```
+/// use smallest_enclosing_circle::{Circle2D, SlidingWindowEnclosingCircle};
+///
+/// let mut window = SlidingWindowEnclosingCircle::with_max_age(5.);
+/// window.push_at([0., 0.], 0.);
+/// window.push_at([4., 0.], 3.);
+/// window.push_at([4., 2.], 6.);
+/// assert!(window.circle().equals(&Circle2D::new(&[[4., 0.], [4., 2.]])));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SlidingWindowEnclosingCircle<Point, T = ()> {
+    dynamic: DynamicEnclosingCircle<Point>,
+    window: VecDeque<(usize, T)>,
+    max_len: usize,
+    max_age: Option<T>,
+}
+
+impl<Point> SlidingWindowEnclosingCircle<Point> {
+    /// Creates an empty window that keeps the last `max_len` points.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `max_len` is zero.
+    pub fn new(max_len: usize) -> Self {
+        if max_len == 0 {
+            panic!("the window must hold at least one point");
+        }
+        SlidingWindowEnclosingCircle {
+            dynamic: DynamicEnclosingCircle::new(),
+            window: VecDeque::new(),
+            max_len,
+            max_age: None,
+        }
+    }
+
+    /// Adds a point to the window, and drops the oldest point if the window is full.
+    pub fn push<C>(&mut self, point: Point)
+    where
+        Point: PartialEq + PointLike<C, 2> + Copy,
+        C: PartialEq,
+        DefaultInCircle: InCircle<C>,
+        DefaultOrientation: Orientation<C>,
+    {
+        self.push_with_predicate::<DefaultInCircle, C>(point)
+    }
+
+    /// See [`SlidingWindowEnclosingCircle::push`]. Additionally, supports a custom [`InCircle`] predicate.
+    pub fn push_with_predicate<InCirclePredicate, C>(&mut self, point: Point)
+    where
+        Point: PartialEq + PointLike<C, 2> + Copy,
+        InCirclePredicate: InCircle<C>,
+        C: PartialEq,
+        DefaultOrientation: Orientation<C>,
+    {
+        self.insert::<InCirclePredicate, C>(point, ());
+    }
+}
+
+impl<Point, T> SlidingWindowEnclosingCircle<Point, T> {
+    /// Creates an empty window that keeps the points whose timestamps are at most `max_age` older than the timestamp of the latest point.
+    ///
+    /// Timestamps can be of any type that supports subtraction, e.g., `f64` seconds, `u64` milliseconds, or [`std::time::Duration`] since some reference instant.
+    pub fn with_max_age(max_age: T) -> Self {
+        SlidingWindowEnclosingCircle {
+            dynamic: DynamicEnclosingCircle::new(),
+            window: VecDeque::new(),
+            max_len: usize::MAX,
+            max_age: Some(max_age),
+        }
+    }
+
+    /// The smallest enclosing circle of all points in the window.
+    pub fn circle(&self) -> &Circle2D<Point> {
+        self.dynamic.circle()
+    }
+
+    /// Iterates over the points in the window, from the oldest to the latest.
+    pub fn points(&self) -> impl Iterator<Item = &Point> {
+        self.window
+            .iter()
+            .filter_map(|(id, _)| self.dynamic.get(*id))
+    }
+
+    /// The number of points in the window.
+    pub fn len(&self) -> usize {
+        self.window.len()
+    }
+
+    /// Whether the window contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+
+    /// Adds a point with the given timestamp to the window, and drops all points that are too old (relative to `timestamp`), or exceed the length of the window.
+    ///
+    /// Timestamps are expected to be non-decreasing.
+    pub fn push_at<C>(&mut self, point: Point, timestamp: T)
+    where
+        Point: PartialEq + PointLike<C, 2> + Copy,
+        T: Sub<Output = T> + PartialOrd + Copy,
+        C: PartialEq,
+        DefaultInCircle: InCircle<C>,
+        DefaultOrientation: Orientation<C>,
+    {
+        self.push_at_with_predicate::<DefaultInCircle, C>(point, timestamp)
+    }
+
+    /// See [`SlidingWindowEnclosingCircle::push_at`]. Additionally, supports a custom [`InCircle`] predicate.
+    pub fn push_at_with_predicate<InCirclePredicate, C>(&mut self, point: Point, timestamp: T)
+    where
+        Point: PartialEq + PointLike<C, 2> + Copy,
+        T: Sub<Output = T> + PartialOrd + Copy,
+        InCirclePredicate: InCircle<C>,
+        C: PartialEq,
+        DefaultOrientation: Orientation<C>,
+    {
+        self.expire_with_predicate::<InCirclePredicate, C>(timestamp);
+        self.insert::<InCirclePredicate, C>(point, timestamp);
+    }
+
+    /// Drops all points that are too old relative to `now`, without adding a new point.
+    pub fn expire<C>(&mut self, now: T)
+    where
+        Point: PartialEq + PointLike<C, 2> + Copy,
+        T: Sub<Output = T> + PartialOrd + Copy,
+        C: PartialEq,
+        DefaultInCircle: InCircle<C>,
+        DefaultOrientation: Orientation<C>,
+    {
+        self.expire_with_predicate::<DefaultInCircle, C>(now)
+    }
+
+    /// See [`SlidingWindowEnclosingCircle::expire`]. Additionally, supports a custom [`InCircle`] predicate.
+    pub fn expire_with_predicate<InCirclePredicate, C>(&mut self, now: T)
+    where
+        Point: PartialEq + PointLike<C, 2> + Copy,
+        T: Sub<Output = T> + PartialOrd + Copy,
+        InCirclePredicate: InCircle<C>,
+        C: PartialEq,
+        DefaultOrientation: Orientation<C>,
+    {
+        let max_age = match self.max_age {
+            Some(max_age) => max_age,
+            None => return,
+        };
+        while let Some(&(id, timestamp)) = self.window.front() {
+            if now < timestamp || now - timestamp <= max_age {
+                break;
+            }
+            self.window.pop_front();
+            self.dynamic
+                .remove_with_predicate::<InCirclePredicate, C>(id);
+        }
+    }
+
+    fn insert<InCirclePredicate, C>(&mut self, point: Point, timestamp: T)
+    where
+        Point: PartialEq + PointLike<C, 2> + Copy,
+        InCirclePredicate: InCircle<C>,
+        C: PartialEq,
+        DefaultOrientation: Orientation<C>,
+    {
+        if self.window.len() == self.max_len {
+            if let Some((id, _)) = self.window.pop_front() {
+                self.dynamic
+                    .remove_with_predicate::<InCirclePredicate, C>(id);
+            }
+        }
+        let id = self
+            .dynamic
+            .insert_with_predicate::<InCirclePredicate, C>(point);
+        self.window.push_back((id, timestamp));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::Duration;
+
+    use crate::{algorithm::smallest_enclosing_circle, random::SplitMix64};
+
+    #[test]
+    fn empty() {
+        let window = SlidingWindowEnclosingCircle::<[f64; 2]>::new(4);
+        assert_eq!(window.circle(), &Circle2D::None);
+        assert!(window.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one point")]
+    fn zero_length() {
+        SlidingWindowEnclosingCircle::<[f64; 2]>::new(0);
+    }
+
+    #[test]
+    fn count_matches_batch() {
+        let mut rng = SplitMix64::new(29);
+        let points: Vec<[f64; 2]> = (0..1000)
+            .map(|_| [rng.next_f64(), rng.next_f64()])
+            .collect();
+        let mut window = SlidingWindowEnclosingCircle::new(50);
+        for (i, point) in points.iter().enumerate() {
+            window.push(*point);
+            if i % 37 == 0 {
+                let expected = smallest_enclosing_circle(points[i.saturating_sub(49)..=i].to_vec());
+                assert!(window.circle().equals(&expected));
+                assert_eq!(window.len(), i.min(49) + 1);
+            }
+        }
+        assert!(window.points().eq(points[950..].iter()));
+    }
+
+    #[test]
+    fn monotone_trajectory() {
+        // The oldest point spans the circle after every push.
+        let points: Vec<[f64; 2]> = (0..500)
+            .map(|t| [t as f64, (t as f64 * 0.37).sin()])
+            .collect();
+        let mut window = SlidingWindowEnclosingCircle::new(50);
+        for (i, point) in points.iter().enumerate() {
+            window.push(*point);
+            let expected = smallest_enclosing_circle(points[i.saturating_sub(49)..=i].to_vec());
+            assert!(window.circle().equals(&expected));
+        }
+    }
+
+    #[test]
+    fn age() {
+        let mut window = SlidingWindowEnclosingCircle::with_max_age(Duration::from_secs(10));
+        window.push_at([0i64, 0], Duration::from_secs(0));
+        window.push_at([10, 0], Duration::from_secs(5));
+        window.push_at([4, 3], Duration::from_secs(10));
+        assert_eq!(window.len(), 3);
+        window.push_at([5, 0], Duration::from_secs(11));
+        assert_eq!(window.len(), 3);
+        assert!(window
+            .circle()
+            .equals(&smallest_enclosing_circle([[10, 0], [4, 3], [5, 0]])));
+        window.expire(Duration::from_secs(30));
+        assert!(window.is_empty());
+        assert_eq!(window.circle(), &Circle2D::None);
+    }
+}
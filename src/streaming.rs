@@ -0,0 +1,182 @@
+use num::traits::real::Real;
+
+use crate::geometry::{
+    num::{ConstTwo, Finite},
+    point::PointLike,
+};
+
+/// Maintains a circle that encloses all points of a stream, using `O(1)` memory and `O(1)` time per point.
+///
+/// Whenever a point lies outside of the current circle, the circle is replaced by the smallest circle that encloses both the current circle and the point.
+/// The radius of the resulting circle is at most `3/2` times the radius of the smallest enclosing circle of all points seen so far.
+/// The radius is rounded upwards after each update, so that the circle encloses all points despite rounding errors.
+/// Points with NaN or infinite coordinates are ignored, as they cannot be enclosed by any circle.
+///
+/// Use [`crate::smallest_enclosing_circle`] instead if all points can be stored.
+///
+/// The algorithm and its approximation bound are described in the following work:
+///
+/// Zarrabi-Zadeh, H., & Chan, T. M. (2006). A simple streaming algorithm for minimum enclosing balls.
+/// In Proceedings of the 18th Canadian Conference on Computational Geometry (pp. 139-142).
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::StreamingEnclosingCircle;
+///
+/// let mut circle = StreamingEnclosingCircle::new();
+/// assert_eq!(circle.current(), None);
+/// for point in [[0., 0.], [1., 0.], [1., 1.], [0., 1.]] {
+///     circle.push(&point);
+/// }
+/// let (center, radius) = circle.current().unwrap();
+/// assert!(radius >= f64::sqrt(2.) / 2. && radius <= 1.5 * f64::sqrt(2.) / 2.);
+/// ```
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct StreamingEnclosingCircle<C> {
+    circle: Option<([C; 2], C)>,
+    count: usize,
+}
+
+impl<C> StreamingEnclosingCircle<C> {
+    /// Creates an empty accumulator.
+    pub fn new() -> Self {
+        StreamingEnclosingCircle {
+            circle: None,
+            count: 0,
+        }
+    }
+
+    /// The number of points pushed so far, excluding ignored points with non-finite coordinates.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl<C> Default for StreamingEnclosingCircle<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C> StreamingEnclosingCircle<C>
+where
+    C: Real + ConstTwo + Finite,
+{
+    /// Adds a point to the stream, and enlarges the circle if necessary. Points with NaN or infinite coordinates are ignored.
+    pub fn push(&mut self, point: &impl PointLike<C, 2>) {
+        let p = point.coordinates();
+        if !p.iter().all(Finite::is_finite) {
+            return;
+        }
+        self.count += 1;
+        self.circle = Some(match self.circle {
+            None => (p, C::zero()),
+            Some((center, radius)) => {
+                let distance = (p[0] - center[0]).hypot(p[1] - center[1]);
+                if distance <= radius {
+                    return;
+                }
+                let new_radius = (radius + distance) / C::TWO;
+                let t = (distance - radius) / (C::TWO * distance);
+                let new_center = [0, 1].map(|i| center[i] + (p[i] - center[i]) * t);
+                // Absorb the rounding errors of the center (relative to the coordinates) and of the radius.
+                let error = C::epsilon()
+                    * (C::TWO * C::TWO * (new_center[0].abs() + new_center[1].abs())
+                        + C::TWO * new_radius);
+                (new_center, new_radius + error)
+            }
+        });
+    }
+
+    /// The current circle (center and radius) that encloses all points pushed so far. `None` if no points were pushed.
+    pub fn current(&self) -> Option<([C; 2], C)> {
+        self.circle
+    }
+}
+
+impl<C, P> Extend<P> for StreamingEnclosingCircle<C>
+where
+    C: Real + ConstTwo + Finite,
+    P: PointLike<C, 2>,
+{
+    fn extend<T: IntoIterator<Item = P>>(&mut self, points: T) {
+        for point in points {
+            self.push(&point);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{algorithm::smallest_enclosing_circle, random::SplitMix64};
+
+    #[test]
+    fn empty() {
+        let circle = StreamingEnclosingCircle::<f64>::default();
+        assert_eq!(circle.current(), None);
+        assert_eq!(circle.count(), 0);
+    }
+
+    #[test]
+    fn single() {
+        let mut circle = StreamingEnclosingCircle::new();
+        circle.push(&[1., 2.]);
+        circle.push(&[1., 2.]);
+        assert_eq!(circle.current(), Some(([1., 2.], 0.)));
+        assert_eq!(circle.count(), 2);
+    }
+
+    #[test]
+    fn two() {
+        let mut circle = StreamingEnclosingCircle::new();
+        circle.extend([[0., 0.], [4., 0.]]);
+        let (center, radius) = circle.current().unwrap();
+        assert_eq!(center, [2., 0.]);
+        assert!((2. ..2. + 1e-12).contains(&radius));
+    }
+
+    #[test]
+    fn approximation() {
+        let mut rng = SplitMix64::new(21);
+        for scale in [1., 1e6] {
+            let points: Vec<[f64; 2]> = (0..5000)
+                .map(|_| [scale + rng.next_f64() * 2., scale + rng.next_f64()])
+                .collect();
+            let mut circle = StreamingEnclosingCircle::new();
+            circle.extend(points.iter());
+            let (center, radius) = circle.current().unwrap();
+            assert!(points
+                .iter()
+                .all(|p| (p[0] - center[0]).hypot(p[1] - center[1]) <= radius));
+            let optimal = smallest_enclosing_circle(points).radius().unwrap();
+            assert!(radius <= 1.5 * optimal * (1. + 1e-9));
+        }
+    }
+
+    #[test]
+    fn non_finite() {
+        let mut circle = StreamingEnclosingCircle::new();
+        circle.extend([
+            [f64::NAN, 0.],
+            [0., 0.],
+            [f64::INFINITY, 1.],
+            [4., 0.],
+            [0., f64::NEG_INFINITY],
+        ]);
+        let (center, radius) = circle.current().unwrap();
+        assert_eq!(center, [2., 0.]);
+        assert!((2. ..2. + 1e-12).contains(&radius));
+        assert_eq!(circle.count(), 2);
+    }
+
+    #[test]
+    fn f32() {
+        let mut circle = StreamingEnclosingCircle::new();
+        circle.extend([[0f32, 0.], [2., 0.], [1., 0.5]]);
+        let (_, radius) = circle.current().unwrap();
+        assert!((1. ..=1.5).contains(&radius));
+    }
+}
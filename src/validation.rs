@@ -0,0 +1,133 @@
+use crate::{
+    algorithm::smallest_enclosing_circle_with_predicate,
+    circle::Circle2D,
+    error::NonFinitePointsError,
+    geometry::{num::Finite, point::PointLike},
+    predicates::{
+        in_circle::{DefaultInCircle, InCircle},
+        orientation::{DefaultOrientation, Orientation},
+    },
+};
+
+/// Determines how [`smallest_enclosing_circle_validated`] treats points with NaN or infinite coordinates.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ValidationPolicy {
+    /// Fails with a [`NonFinitePointsError`] that lists all offending points.
+    Reject,
+    /// Ignores the offending points and computes the circle of the remaining points. The ignored points are reported in [`ValidatedEnclosingCircle::skipped_indices`].
+    Skip,
+}
+
+/// The result of [`smallest_enclosing_circle_validated`].
+#[derive(PartialEq, Clone, Debug)]
+pub struct ValidatedEnclosingCircle<Point> {
+    /// The smallest enclosing circle of all valid points.
+    pub circle: Circle2D<Point>,
+    /// Indices (positions in the input iterator) of the points that were skipped because of NaN or infinite coordinates, in ascending order. Always empty for [`ValidationPolicy::Reject`].
+    pub skipped_indices: Vec<usize>,
+}
+
+/// See [`smallest_enclosing_circle_validated`]. Additionally, supports a custom [`InCircle`] predicate.
+pub fn smallest_enclosing_circle_validated_with_predicate<Point, InCirclePredicate, C>(
+    points: impl IntoIterator<Item = Point>,
+    policy: ValidationPolicy,
+) -> Result<ValidatedEnclosingCircle<Point>, NonFinitePointsError>
+where
+    Point: PartialEq + PointLike<C, 2> + Copy,
+    InCirclePredicate: InCircle<C>,
+    C: PartialEq + Finite,
+    DefaultOrientation: Orientation<C>,
+{
+    let mut valid = Vec::new();
+    let mut invalid = Vec::new();
+    for (index, point) in points.into_iter().enumerate() {
+        if point.coordinates().iter().all(|x| x.is_finite()) {
+            valid.push(point);
+        } else {
+            invalid.push(index);
+        }
+    }
+
+    if policy == ValidationPolicy::Reject && !invalid.is_empty() {
+        return Err(NonFinitePointsError { indices: invalid });
+    }
+
+    Ok(ValidatedEnclosingCircle {
+        circle: smallest_enclosing_circle_with_predicate::<Point, InCirclePredicate, C>(valid),
+        skipped_indices: invalid,
+    })
+}
+
+/// Same as [`crate::smallest_enclosing_circle`], but checks the input for points with NaN or infinite coordinates first.
+///
+/// The robust predicates used by the algorithm are only meaningful for finite coordinates, so without validation such points silently lead to a wrong circle.
+/// Depending on the [`ValidationPolicy`], the offending points are either rejected (the function returns a [`NonFinitePointsError`] listing their indices) or skipped (their indices are reported alongside the circle).
+///
+/// # Examples
+///
+/// ```
+/// use smallest_enclosing_circle::validation::{smallest_enclosing_circle_validated, ValidationPolicy};
+///
+/// let points = [[0., 0.], [f64::NAN, 0.], [1., 0.], [0., f64::INFINITY]];
+///
+/// let error = smallest_enclosing_circle_validated(points, ValidationPolicy::Reject).unwrap_err();
+/// assert_eq!(error.indices, vec![1, 3]);
+///
+/// let result = smallest_enclosing_circle_validated(points, ValidationPolicy::Skip).unwrap();
+/// assert_eq!(result.circle.center(), Some([0.5, 0.]));
+/// assert_eq!(result.skipped_indices, vec![1, 3]);
+/// ```
+pub fn smallest_enclosing_circle_validated<Point, C>(
+    points: impl IntoIterator<Item = Point>,
+    policy: ValidationPolicy,
+) -> Result<ValidatedEnclosingCircle<Point>, NonFinitePointsError>
+where
+    Point: PartialEq + PointLike<C, 2> + Copy,
+    C: PartialEq + Finite,
+    DefaultInCircle: InCircle<C>,
+    DefaultOrientation: Orientation<C>,
+{
+    smallest_enclosing_circle_validated_with_predicate::<Point, DefaultInCircle, C>(points, policy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_input() {
+        let points = [[0., 0.], [1., 0.], [1., 1.]];
+        for policy in [ValidationPolicy::Reject, ValidationPolicy::Skip] {
+            let result = smallest_enclosing_circle_validated(points, policy).unwrap();
+            assert!(result.circle.equals(&Circle2D::new(&[[0., 0.], [1., 1.]])));
+            assert!(result.skipped_indices.is_empty());
+        }
+    }
+
+    #[test]
+    fn reject() {
+        let points = [[f64::NAN, 0.], [1., 0.], [1., f64::NEG_INFINITY]];
+        assert_eq!(
+            smallest_enclosing_circle_validated(points, ValidationPolicy::Reject),
+            Err(NonFinitePointsError {
+                indices: vec![0, 2]
+            })
+        );
+    }
+
+    #[test]
+    fn skip() {
+        let points = [[f64::NAN, 0.], [1., 0.], [5., 5.], [1., f64::NEG_INFINITY]];
+        let result = smallest_enclosing_circle_validated(points, ValidationPolicy::Skip).unwrap();
+        assert!(result.circle.equals(&Circle2D::new(&[[1., 0.], [5., 5.]])));
+        assert_eq!(result.skipped_indices, vec![0, 3]);
+    }
+
+    #[test]
+    fn skip_all() {
+        let points = [[f64::NAN, f64::NAN]];
+        let result = smallest_enclosing_circle_validated(points, ValidationPolicy::Skip).unwrap();
+        assert_eq!(result.circle, Circle2D::None);
+        assert_eq!(result.skipped_indices, vec![0]);
+    }
+}